@@ -0,0 +1,111 @@
+//! Functions summarising collections of vectors
+//!
+//! Each function takes anything iterable over vectors or references to vectors,
+//! so both `centroid(&points)` and `centroid(points.iter().map(..))` work.
+
+use super::Vector2;
+
+use num_traits::{Float, Zero, One};
+
+use core::borrow::Borrow;
+use core::iter::{Sum, Product};
+use core::ops::{Add, Mul};
+
+impl<T: Add<Output=T> + Zero> Sum for Vector2<T>{
+    fn sum<I: Iterator<Item=Self>>(iter: I) -> Self{
+        iter.fold(Vector2(T::zero(), T::zero()), Add::add)
+    }
+}
+
+impl<'a, T: Add<Output=T> + Zero + Copy> Sum<&'a Vector2<T>> for Vector2<T>{
+    fn sum<I: Iterator<Item=&'a Self>>(iter: I) -> Self{
+        iter.cloned().sum()
+    }
+}
+
+/// Multiplies the vectors component-wise
+impl<T: Mul<Output=T> + One> Product for Vector2<T>{
+    fn product<I: Iterator<Item=Self>>(iter: I) -> Self{
+        iter.fold(Vector2(T::one(), T::one()), Mul::mul)
+    }
+}
+
+/// Multiplies the vectors component-wise
+impl<'a, T: Mul<Output=T> + One + Copy> Product<&'a Vector2<T>> for Vector2<T>{
+    fn product<I: Iterator<Item=&'a Self>>(iter: I) -> Self{
+        iter.cloned().product()
+    }
+}
+
+/// Returns the average of the points or `None` if there are none
+pub fn centroid<T, I>(points: I) -> Option<Vector2<T>>
+where T: Float, I: IntoIterator, I::Item: Borrow<Vector2<T>>{
+    let (sum, count) = points.into_iter()
+        .fold((Vector2(T::zero(), T::zero()), T::zero()), |(sum, count), p| (sum + *p.borrow(), count + T::one()));
+    if count.is_zero() {
+        None
+    } else {
+        Some(sum / count)
+    }
+}
+
+/// Returns the average of the points weighted by their respective weights
+///
+/// Returns `None` if the weights add up to zero.
+pub fn weighted_centroid<T, I, P>(points: I) -> Option<Vector2<T>>
+where T: Float, I: IntoIterator<Item=(P, T)>, P: Borrow<Vector2<T>>{
+    let (sum, total) = points.into_iter()
+        .fold((Vector2(T::zero(), T::zero()), T::zero()), |(sum, total), (p, w)| (sum + *p.borrow() * w, total + w));
+    if total.is_zero() {
+        None
+    } else {
+        Some(sum / total)
+    }
+}
+
+/// Returns the smallest and largest corners of the axis-aligned box containing all the points
+///
+/// Returns `None` if there are no points.
+pub fn bounding_box<T, I>(points: I) -> Option<(Vector2<T>, Vector2<T>)>
+where T: PartialOrd + Copy, I: IntoIterator, I::Item: Borrow<Vector2<T>>{
+    let mut points = points.into_iter();
+    let first = *points.next()?.borrow();
+    Some(points.fold((first, first), |(min, max), p| (min.min(*p.borrow()), max.max(*p.borrow()))))
+}
+
+/// Returns the shortest vector or `None` if there are none
+pub fn min_by_length<T, I>(vectors: I) -> Option<Vector2<T>>
+where T: Float, I: IntoIterator, I::Item: Borrow<Vector2<T>>{
+    min_by_key(vectors, Vector2::length_squared)
+}
+
+/// Returns the point closest to `query` or `None` if there are no points
+pub fn nearest<T, I>(points: I, query: Vector2<T>) -> Option<Vector2<T>>
+where T: Float, I: IntoIterator, I::Item: Borrow<Vector2<T>>{
+    min_by_key(points, |p| p.distance_to_squared(query))
+}
+
+/// Returns the point farthest from `query` or `None` if there are no points
+pub fn farthest<T, I>(points: I, query: Vector2<T>) -> Option<Vector2<T>>
+where T: Float, I: IntoIterator, I::Item: Borrow<Vector2<T>>{
+    min_by_key(points, |p| -p.distance_to_squared(query))
+}
+
+// Like `Iterator::min_by_key` but for float keys, keeping the first minimum
+// and only returning a vector with a `NaN` key if every key is `NaN`
+fn min_by_key<T, I, F>(vectors: I, mut key: F) -> Option<Vector2<T>>
+where T: Float, I: IntoIterator, I::Item: Borrow<Vector2<T>>, F: FnMut(Vector2<T>) -> T{
+    let mut best: Option<(Vector2<T>, T)> = None;
+    for v in vectors {
+        let v = *v.borrow();
+        let k = key(v);
+        let better = match best {
+            Some((_, best_key)) => k < best_key || (best_key.is_nan() && !k.is_nan()),
+            None => true,
+        };
+        if better {
+            best = Some((v, k));
+        }
+    }
+    best.map(|(v, _)| v)
+}
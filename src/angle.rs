@@ -0,0 +1,126 @@
+use super::Vector2;
+
+use num_traits::{Float, FloatConst};
+
+use core::ops::{Add, AddAssign, Sub, SubAssign, Mul, Div, Neg};
+
+/// An angle in radians
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Default)]
+#[cfg_attr(feature="rustc-serialize", derive(RustcDecodable, RustcEncodable))]
+#[cfg_attr(feature="serde_derive", derive(Serialize, Deserialize))]
+pub struct Rad<T>(pub T);
+
+/// An angle in degrees
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Default)]
+#[cfg_attr(feature="rustc-serialize", derive(RustcDecodable, RustcEncodable))]
+#[cfg_attr(feature="serde_derive", derive(Serialize, Deserialize))]
+pub struct Deg<T>(pub T);
+
+macro_rules! impl_angle {
+    ($A:ident, $turn:expr, $half:expr) => {
+        impl<T: Float + FloatConst> $A<T>{
+            /// Returns the equivalent angle in the range `[0, full turn)`
+            pub fn wrap_positive(self) -> Self{
+                let turn = $turn;
+                let r = self.0 % turn;
+                let r = if r < T::zero() { r + turn } else { r };
+                // Adding a full turn to a tiny negative number can round up to a full turn
+                $A(if r >= turn { T::zero() } else { r })
+            }
+            /// Returns the equivalent angle in the range `(-half turn, half turn]`
+            pub fn wrap_signed(self) -> Self{
+                let r = self.wrap_positive().0;
+                $A(if r > $half { r - $turn } else { r })
+            }
+            /// Returns the signed difference to turn from this angle to `other` the short way round
+            ///
+            /// The result lies in the range `(-half turn, half turn]`.
+            pub fn shortest_difference(self, other: Self) -> Self{
+                (other - self).wrap_signed()
+            }
+        }
+
+        impl<T: Add> Add for $A<T>{
+            type Output = $A<T::Output>;
+
+            fn add(self, rhs: Self) -> Self::Output{
+                $A(self.0 + rhs.0)
+            }
+        }
+
+        impl<T: Sub> Sub for $A<T>{
+            type Output = $A<T::Output>;
+
+            fn sub(self, rhs: Self) -> Self::Output{
+                $A(self.0 - rhs.0)
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $A<T>{
+            fn add_assign(&mut self, rhs: Self){
+                self.0 += rhs.0;
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $A<T>{
+            fn sub_assign(&mut self, rhs: Self){
+                self.0 -= rhs.0;
+            }
+        }
+
+        impl<T: Mul> Mul<T> for $A<T>{
+            type Output = $A<T::Output>;
+
+            fn mul(self, rhs: T) -> Self::Output{
+                $A(self.0 * rhs)
+            }
+        }
+
+        impl<T: Div> Div<T> for $A<T>{
+            type Output = $A<T::Output>;
+
+            fn div(self, rhs: T) -> Self::Output{
+                $A(self.0 / rhs)
+            }
+        }
+
+        impl<T: Neg> Neg for $A<T>{
+            type Output = $A<T::Output>;
+
+            fn neg(self) -> Self::Output{
+                $A(-self.0)
+            }
+        }
+    };
+}
+impl_angle!(Rad, T::TAU(), T::PI());
+impl_angle!(Deg, T::from(360).unwrap(), T::from(180).unwrap());
+
+impl<T: Float> From<Deg<T>> for Rad<T>{
+    #[inline]
+    fn from(deg: Deg<T>) -> Self{
+        Rad(deg.0.to_radians())
+    }
+}
+
+impl<T: Float> From<Rad<T>> for Deg<T>{
+    #[inline]
+    fn from(rad: Rad<T>) -> Self{
+        Deg(rad.0.to_degrees())
+    }
+}
+
+impl<T: Float> Vector2<T>{
+    /// Creates a new unit vector in the direction of an angle in either radians or degrees
+    pub fn from_angle<A: Into<Rad<T>>>(angle: A) -> Self{
+        Vector2::unit_vector(angle.into().0)
+    }
+    /// Returns the angle the vector is pointing
+    pub fn angle(self) -> Rad<T>{
+        Rad(self.direction())
+    }
+    /// Returns the angle towards another vector
+    pub fn angle_to(self, other: Self) -> Rad<T>{
+        Rad(self.direction_to(other))
+    }
+}
@@ -0,0 +1,151 @@
+use super::Vector2;
+
+/// Equality comparisons that tolerate the rounding errors of floating point math
+pub trait ApproxEq{
+    /// The type tolerances are expressed in
+    type Epsilon: Copy;
+
+    /// The absolute tolerance used when none is given
+    fn default_epsilon() -> Self::Epsilon;
+    /// The relative tolerance used when none is given
+    fn default_max_relative() -> Self::Epsilon;
+    /// The tolerance in units in the last place used when none is given
+    fn default_max_ulps() -> u32;
+
+    /// Returns `true` if the absolute difference is at most `epsilon`
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool;
+    /// Returns `true` if the difference is at most `epsilon` or at most
+    /// `max_relative` times the larger of the two magnitudes
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool;
+    /// Returns `true` if the difference is at most `epsilon` or
+    /// at most `max_ulps` representable numbers apart
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool;
+
+    /// Returns `true` if the values are relatively equal with the default tolerances
+    fn approx_eq(&self, other: &Self) -> bool{
+        self.relative_eq(other, Self::default_epsilon(), Self::default_max_relative())
+    }
+}
+
+macro_rules! impl_approx_eq {
+    ($($t:ident: $bits:ty),*) => {$(
+        impl ApproxEq for $t{
+            type Epsilon = $t;
+
+            fn default_epsilon() -> $t{
+                $t::EPSILON
+            }
+            fn default_max_relative() -> $t{
+                $t::EPSILON
+            }
+            fn default_max_ulps() -> u32{
+                4
+            }
+
+            fn abs_diff_eq(&self, other: &$t, epsilon: $t) -> bool{
+                (self - other).abs() <= epsilon
+            }
+            fn relative_eq(&self, other: &$t, epsilon: $t, max_relative: $t) -> bool{
+                if self == other {
+                    return true;
+                }
+                if self.is_infinite() || other.is_infinite() {
+                    return false;
+                }
+                let diff = (self - other).abs();
+                diff <= epsilon || diff <= self.abs().max(other.abs()) * max_relative
+            }
+            fn ulps_eq(&self, other: &$t, epsilon: $t, max_ulps: u32) -> bool{
+                if self.is_nan() || other.is_nan() {
+                    return false;
+                }
+                // Infinities are next to the largest finite numbers bitwise
+                if self.is_infinite() || other.is_infinite() {
+                    return self == other;
+                }
+                if self.abs_diff_eq(other, epsilon) {
+                    return true;
+                }
+                if self.is_sign_negative() != other.is_sign_negative() {
+                    return false;
+                }
+                let diff = (self.to_bits() as $bits).wrapping_sub(other.to_bits() as $bits);
+                diff.unsigned_abs() <= max_ulps as _
+            }
+        }
+    )*};
+}
+impl_approx_eq!{f32: i32, f64: i64}
+
+impl<T: ApproxEq> ApproxEq for Vector2<T>{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> T::Epsilon{
+        T::default_epsilon()
+    }
+    fn default_max_relative() -> T::Epsilon{
+        T::default_max_relative()
+    }
+    fn default_max_ulps() -> u32{
+        T::default_max_ulps()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool{
+        self.0.abs_diff_eq(&other.0, epsilon) && self.1.abs_diff_eq(&other.1, epsilon)
+    }
+    fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool{
+        self.0.relative_eq(&other.0, epsilon, max_relative) && self.1.relative_eq(&other.1, epsilon, max_relative)
+    }
+    fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool{
+        self.0.ulps_eq(&other.0, epsilon, max_ulps) && self.1.ulps_eq(&other.1, epsilon, max_ulps)
+    }
+}
+
+/// Asserts that two vectors are approximately equal
+///
+/// By default the vectors are compared with `ApproxEq::relative_eq` and the default tolerances.
+/// A tolerance can be given as `epsilon = ...` for an absolute comparison,
+/// `max_relative = ...` for a relative comparison or `max_ulps = ...` for an ULPs comparison.
+///
+/// ```
+/// # #[macro_use] extern crate simple_vector2d;
+/// # use simple_vector2d::Vector2;
+/// # fn main(){
+/// let v = Vector2(0.1 + 0.2, 1.);
+/// assert_vec_approx_eq!(v, Vector2(0.3, 1.));
+/// assert_vec_approx_eq!(v, Vector2(0.3, 1.), epsilon = 1e-12);
+/// assert_vec_approx_eq!(v, Vector2(0.3, 1.), max_ulps = 1);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_vec_approx_eq {
+    ($left:expr, $right:expr) => {
+        $crate::assert_vec_approx_eq!(@assert $left, $right, l, r, $crate::ApproxEq::approx_eq(l, r))
+    };
+    ($left:expr, $right:expr, epsilon = $eps:expr) => {
+        $crate::assert_vec_approx_eq!(@assert $left, $right, l, r, $crate::ApproxEq::abs_diff_eq(l, r, $eps))
+    };
+    ($left:expr, $right:expr, max_relative = $rel:expr) => {
+        $crate::assert_vec_approx_eq!(@assert $left, $right, l, r, $crate::__relative_eq(l, r, $rel))
+    };
+    ($left:expr, $right:expr, max_ulps = $ulps:expr) => {
+        $crate::assert_vec_approx_eq!(@assert $left, $right, l, r, $crate::__ulps_eq(l, r, $ulps))
+    };
+    (@assert $left:expr, $right:expr, $l:ident, $r:ident, $eq:expr) => {
+        match (&$left, &$right) {
+            ($l, $r) => if !$eq {
+                panic!("assertion failed: `left ≈ right`\n  left: `{:?}`\n right: `{:?}`", $l, $r)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+pub fn __relative_eq<T: ApproxEq>(left: &T, right: &T, max_relative: T::Epsilon) -> bool{
+    left.relative_eq(right, T::default_epsilon(), max_relative)
+}
+
+#[doc(hidden)]
+pub fn __ulps_eq<T: ApproxEq>(left: &T, right: &T, max_ulps: u32) -> bool{
+    left.ulps_eq(right, T::default_epsilon(), max_ulps)
+}
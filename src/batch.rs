@@ -0,0 +1,262 @@
+//! Packed vectors for processing many `Vector2<f32>` at once
+//!
+//! `Vector2x4` and `Vector2x8` store their lanes as a struct of arrays,
+//! i.e. all the x components followed by all the y components.
+//! With the nightly-only `simd` feature the lanes are processed with `core::simd`,
+//! otherwise plain loops over the arrays are used, which the compiler usually vectorises.
+//!
+//! ```
+//! use simple_vector2d::Vector2;
+//! use simple_vector2d::batch::Vector2x4;
+//!
+//! let mut positions = Vector2x4::from([Vector2(0., 0.), Vector2(1., 0.), Vector2(0., 1.), Vector2(1., 1.)]);
+//! let velocity = Vector2x4::splat(Vector2(0.5, -1.));
+//! positions += velocity * 2.;
+//! assert_eq!(positions.extract(3), Vector2(2., -1.));
+//! ```
+
+use super::Vector2;
+
+use num_traits::Float;
+
+use core::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg};
+
+// Applies an operator lane by lane
+#[cfg(feature="simd")]
+macro_rules! lanes {
+    ($n:expr; $op:tt $a:expr) => {
+        ($op core::simd::Simd::<f32, $n>::from_array($a)).to_array()
+    };
+    ($n:expr; $a:expr, $op:tt $b:expr) => {
+        (core::simd::Simd::<f32, $n>::from_array($a) $op core::simd::Simd::<f32, $n>::from_array($b)).to_array()
+    };
+}
+
+#[cfg(not(feature="simd"))]
+macro_rules! lanes {
+    ($n:expr; $op:tt $a:expr) => {{
+        let a: [f32; $n] = $a;
+        let mut r = [0.; $n];
+        for (r, a) in r.iter_mut().zip(a.iter()) {
+            *r = $op *a;
+        }
+        r
+    }};
+    ($n:expr; $a:expr, $op:tt $b:expr) => {{
+        let (a, b): ([f32; $n], [f32; $n]) = ($a, $b);
+        let mut r = [0.; $n];
+        for ((r, a), b) in r.iter_mut().zip(a.iter()).zip(b.iter()) {
+            *r = *a $op *b;
+        }
+        r
+    }};
+}
+
+macro_rules! batch {
+    ($(#[$attr:meta])* $V:ident, $n:expr) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Debug, PartialEq, Default)]
+        #[cfg_attr(feature="serde_derive", derive(Serialize, Deserialize))]
+        pub struct $V(pub [f32; $n], pub [f32; $n]);
+
+        impl $V{
+            /// The number of vectors packed together
+            pub const LANES: usize = $n;
+
+            /// Creates a batch with every lane set to the same vector
+            pub fn splat(vector: Vector2<f32>) -> Self{
+                $V([vector.0; $n], [vector.1; $n])
+            }
+            /// Returns the vector in a lane
+            ///
+            /// Panics if `lane` is out of bounds.
+            pub fn extract(self, lane: usize) -> Vector2<f32>{
+                Vector2(self.0[lane], self.1[lane])
+            }
+            /// Sets the vector in a lane
+            ///
+            /// Panics if `lane` is out of bounds.
+            pub fn replace(&mut self, lane: usize, vector: Vector2<f32>){
+                self.0[lane] = vector.0;
+                self.1[lane] = vector.1;
+            }
+
+            /// Returns the dot product of each pair of lanes
+            pub fn dot(self, other: Self) -> [f32; $n]{
+                let (x, y) = (lanes!($n; self.0, * other.0), lanes!($n; self.1, * other.1));
+                lanes!($n; x, + y)
+            }
+            /// Returns the determinant of each pair of lanes
+            pub fn det(self, other: Self) -> [f32; $n]{
+                let (a, b) = (lanes!($n; self.0, * other.1), lanes!($n; self.1, * other.0));
+                lanes!($n; a, - b)
+            }
+            /// Returns the magnitude/length of each lane squared
+            pub fn length_squared(self) -> [f32; $n]{
+                self.dot(self)
+            }
+            /// Returns the magnitude/length of each lane
+            pub fn length(self) -> [f32; $n]{
+                let mut length = self.length_squared();
+                for l in length.iter_mut() {
+                    *l = Float::sqrt(*l);
+                }
+                length
+            }
+            /// Normalises each lane
+            pub fn normalise(self) -> Self{
+                let length = self.length();
+                $V(lanes!($n; self.0, / length), lanes!($n; self.1, / length))
+            }
+        }
+
+        impl From<[Vector2<f32>; $n]> for $V{
+            fn from(vectors: [Vector2<f32>; $n]) -> Self{
+                let mut batch = $V::default();
+                for (lane, v) in vectors.iter().enumerate() {
+                    batch.replace(lane, *v);
+                }
+                batch
+            }
+        }
+
+        impl From<$V> for [Vector2<f32>; $n]{
+            fn from(batch: $V) -> Self{
+                let mut vectors = [Vector2(0., 0.); $n];
+                for (lane, v) in vectors.iter_mut().enumerate() {
+                    *v = batch.extract(lane);
+                }
+                vectors
+            }
+        }
+
+        impl Add for $V{
+            type Output = $V;
+
+            fn add(self, rhs: Self) -> Self{
+                $V(lanes!($n; self.0, + rhs.0), lanes!($n; self.1, + rhs.1))
+            }
+        }
+
+        impl Sub for $V{
+            type Output = $V;
+
+            fn sub(self, rhs: Self) -> Self{
+                $V(lanes!($n; self.0, - rhs.0), lanes!($n; self.1, - rhs.1))
+            }
+        }
+
+        impl Mul<f32> for $V{
+            type Output = $V;
+
+            fn mul(self, rhs: f32) -> Self{
+                $V(lanes!($n; self.0, * [rhs; $n]), lanes!($n; self.1, * [rhs; $n]))
+            }
+        }
+
+        /// Multiplies the lanes component-wise
+        impl Mul for $V{
+            type Output = $V;
+
+            fn mul(self, rhs: Self) -> Self{
+                $V(lanes!($n; self.0, * rhs.0), lanes!($n; self.1, * rhs.1))
+            }
+        }
+
+        /// Multiplies each lane by its own scalar
+        impl Mul<[f32; $n]> for $V{
+            type Output = $V;
+
+            fn mul(self, rhs: [f32; $n]) -> Self{
+                $V(lanes!($n; self.0, * rhs), lanes!($n; self.1, * rhs))
+            }
+        }
+
+        impl Mul<$V> for f32{
+            type Output = $V;
+
+            fn mul(self, rhs: $V) -> $V{
+                rhs * self
+            }
+        }
+
+        impl Div<f32> for $V{
+            type Output = $V;
+
+            fn div(self, rhs: f32) -> Self{
+                $V(lanes!($n; self.0, / [rhs; $n]), lanes!($n; self.1, / [rhs; $n]))
+            }
+        }
+
+        /// Divides the lanes component-wise
+        impl Div for $V{
+            type Output = $V;
+
+            fn div(self, rhs: Self) -> Self{
+                $V(lanes!($n; self.0, / rhs.0), lanes!($n; self.1, / rhs.1))
+            }
+        }
+
+        /// Divides each lane by its own scalar
+        impl Div<[f32; $n]> for $V{
+            type Output = $V;
+
+            fn div(self, rhs: [f32; $n]) -> Self{
+                $V(lanes!($n; self.0, / rhs), lanes!($n; self.1, / rhs))
+            }
+        }
+
+        impl Neg for $V{
+            type Output = $V;
+
+            fn neg(self) -> Self{
+                $V(lanes!($n; - self.0), lanes!($n; - self.1))
+            }
+        }
+
+        impl AddAssign for $V{
+            fn add_assign(&mut self, rhs: Self){
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for $V{
+            fn sub_assign(&mut self, rhs: Self){
+                *self = *self - rhs;
+            }
+        }
+
+        impl MulAssign<f32> for $V{
+            fn mul_assign(&mut self, rhs: f32){
+                *self = *self * rhs;
+            }
+        }
+
+        impl DivAssign<f32> for $V{
+            fn div_assign(&mut self, rhs: f32){
+                *self = *self / rhs;
+            }
+        }
+
+        impl MulAssign for $V{
+            fn mul_assign(&mut self, rhs: Self){
+                *self = *self * rhs;
+            }
+        }
+
+        impl DivAssign for $V{
+            fn div_assign(&mut self, rhs: Self){
+                *self = *self / rhs;
+            }
+        }
+    };
+}
+
+batch!{
+    /// Four `Vector2<f32>` packed into lanes, x components first
+    Vector2x4, 4
+}
+batch!{
+    /// Eight `Vector2<f32>` packed into lanes, x components first
+    Vector2x8, 8
+}
@@ -0,0 +1,69 @@
+use super::Vector2;
+
+use num_traits::{Float, ToPrimitive, NumCast, AsPrimitive};
+
+impl<T: ToPrimitive> Vector2<T>{
+    /// Converts the components to another numeric type,
+    /// returning `None` if either component cannot be represented
+    pub fn cast<U: NumCast>(self) -> Option<Vector2<U>>{
+        Some(Vector2(U::from(self.0)?, U::from(self.1)?))
+    }
+}
+
+impl<T: AsPrimitive<f32>> Vector2<T>{
+    /// Converts the components to `f32` like the `as` operator
+    pub fn as_f32(self) -> Vector2<f32>{
+        Vector2(self.0.as_(), self.1.as_())
+    }
+}
+
+impl<T: AsPrimitive<f64>> Vector2<T>{
+    /// Converts the components to `f64` like the `as` operator
+    pub fn as_f64(self) -> Vector2<f64>{
+        Vector2(self.0.as_(), self.1.as_())
+    }
+}
+
+impl<T: AsPrimitive<i32>> Vector2<T>{
+    /// Converts the components to `i32` like the `as` operator
+    ///
+    /// Floats are truncated towards zero and saturate at the bounds of `i32`, `NaN` becomes zero.
+    pub fn as_i32(self) -> Vector2<i32>{
+        Vector2(self.0.as_(), self.1.as_())
+    }
+}
+
+impl<T: Float + AsPrimitive<i32>> Vector2<T>{
+    /// Rounds the components to the nearest `i32`, saturating at the bounds of `i32`
+    pub fn round_to_i32(self) -> Vector2<i32>{
+        self.round().as_i32()
+    }
+    /// Rounds the components down to an `i32`, saturating at the bounds of `i32`
+    ///
+    /// Useful for finding the tile a position lies in.
+    pub fn floor_to_i32(self) -> Vector2<i32>{
+        self.floor().as_i32()
+    }
+}
+
+macro_rules! impl_widening {
+    ($($from:ty => $($to:ty),+;)*) => {$($(
+        impl From<Vector2<$from>> for Vector2<$to>{
+            #[inline]
+            fn from(v: Vector2<$from>) -> Self{
+                Vector2(<$to as From<$from>>::from(v.0), <$to as From<$from>>::from(v.1))
+            }
+        }
+    )+)*};
+}
+impl_widening!{
+    i8 => i16, i32, i64, i128, f32, f64;
+    i16 => i32, i64, i128, f32, f64;
+    i32 => i64, i128, f64;
+    i64 => i128;
+    u8 => u16, u32, u64, u128, i16, i32, i64, i128, f32, f64;
+    u16 => u32, u64, u128, i32, i64, i128, f32, f64;
+    u32 => u64, u128, i64, i128, f64;
+    u64 => u128, i128;
+    f32 => f64;
+}
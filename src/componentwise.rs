@@ -0,0 +1,105 @@
+use super::Vector2;
+
+use num_traits::{Float, Signed};
+
+use core::ops::{Mul, MulAssign, Div, DivAssign};
+
+impl<T> Vector2<T>{
+    /// Applies a function to each component
+    pub fn map<U, F: FnMut(T) -> U>(self, mut f: F) -> Vector2<U>{
+        Vector2(f(self.0), f(self.1))
+    }
+    /// Applies a function to each pair of components of two vectors
+    pub fn zip_with<U, V, F: FnMut(T, U) -> V>(self, other: Vector2<U>, mut f: F) -> Vector2<V>{
+        Vector2(f(self.0, other.0), f(self.1, other.1))
+    }
+}
+
+impl<T: PartialOrd> Vector2<T>{
+    /// Returns the component-wise minimum of two vectors
+    pub fn min(self, other: Self) -> Self{
+        self.zip_with(other, |a, b| if b < a { b } else { a })
+    }
+    /// Returns the component-wise maximum of two vectors
+    pub fn max(self, other: Self) -> Self{
+        self.zip_with(other, |a, b| if b > a { b } else { a })
+    }
+    /// Restricts each component to the range given by the components of `min` and `max`
+    pub fn clamp(self, min: Self, max: Self) -> Self{
+        self.max(min).min(max)
+    }
+    /// Returns the smaller of the two components
+    pub fn min_element(self) -> T{
+        if self.1 < self.0 { self.1 } else { self.0 }
+    }
+    /// Returns the larger of the two components
+    pub fn max_element(self) -> T{
+        if self.1 > self.0 { self.1 } else { self.0 }
+    }
+}
+
+impl<T: Signed> Vector2<T>{
+    /// Returns the absolute value of each component
+    pub fn abs(self) -> Self{
+        self.map(|c| c.abs())
+    }
+    /// Returns the sign of each component
+    pub fn signum(self) -> Self{
+        self.map(|c| c.signum())
+    }
+}
+
+impl<T: Float> Vector2<T>{
+    /// Rounds each component down
+    pub fn floor(self) -> Self{
+        self.map(Float::floor)
+    }
+    /// Rounds each component up
+    pub fn ceil(self) -> Self{
+        self.map(Float::ceil)
+    }
+    /// Rounds each component to the nearest integer, away from zero on a tie
+    pub fn round(self) -> Self{
+        self.map(Float::round)
+    }
+    /// Returns the reciprocal of each component
+    pub fn recip(self) -> Self{
+        self.map(Float::recip)
+    }
+    /// Computes `self * a + b` component-wise with only one rounding error
+    pub fn mul_add(self, a: Self, b: Self) -> Self{
+        Vector2(self.0.mul_add(a.0, b.0), self.1.mul_add(a.1, b.1))
+    }
+}
+
+impl<T: Mul> Mul for Vector2<T>{
+    type Output = Vector2<T::Output>;
+
+    /// Multiplies the vectors component-wise
+    fn mul(self, rhs: Self) -> Self::Output{
+        Vector2(self.0 * rhs.0, self.1 * rhs.1)
+    }
+}
+
+impl<T: Div> Div for Vector2<T>{
+    type Output = Vector2<T::Output>;
+
+    /// Divides the vectors component-wise
+    fn div(self, rhs: Self) -> Self::Output{
+        Vector2(self.0 / rhs.0, self.1 / rhs.1)
+    }
+}
+
+impl<T: MulAssign> MulAssign for Vector2<T>{
+    fn mul_assign(&mut self, rhs: Self){
+        self.0 *= rhs.0;
+        self.1 *= rhs.1;
+    }
+}
+
+impl<T: DivAssign> DivAssign for Vector2<T>{
+    fn div_assign(&mut self, rhs: Self){
+        self.0 /= rhs.0;
+        self.1 /= rhs.1;
+    }
+}
@@ -0,0 +1,87 @@
+//! Coordinate-system conventions
+//!
+//! The methods on `Vector2` itself assume the y-axis points up, as is usual in world space.
+//! Screen space usually has the y-axis pointing down, which mirrors what "up" and
+//! "counter-clockwise" mean. The marker types here describe which way the y-axis points,
+//! so directions and rotations can be given as they appear on screen.
+//!
+//! ```
+//! use simple_vector2d::Vector2;
+//! use simple_vector2d::convention::{Convention, YUp, YDown};
+//!
+//! assert_eq!(YUp::up::<f32>(), Vector2(0., 1.));
+//! assert_eq!(YDown::up::<f32>(), Vector2(0., -1.));
+//! assert_eq!(YUp::convert::<YDown, _>(Vector2(3, 4)), Vector2(3, -4));
+//! ```
+
+use super::{Vector2, Real};
+use super::consts::ConstSigned;
+
+use core::ops::{Sub, Neg};
+
+/// A description of which way the y-axis points
+pub trait Convention{
+    /// Whether the y-axis points up
+    const Y_UP: bool;
+
+    /// A unit vector pointing up
+    fn up<T: ConstSigned>() -> Vector2<T>{
+        if Self::Y_UP { Vector2::Y } else { Vector2::NEG_Y }
+    }
+    /// A unit vector pointing down
+    fn down<T: ConstSigned>() -> Vector2<T>{
+        if Self::Y_UP { Vector2::NEG_Y } else { Vector2::Y }
+    }
+    /// A unit vector pointing to the left
+    fn left<T: ConstSigned>() -> Vector2<T>{
+        Vector2::NEG_X
+    }
+    /// A unit vector pointing to the right
+    fn right<T: ConstSigned>() -> Vector2<T>{
+        Vector2::X
+    }
+    /// Returns the sign of a counter-clockwise angle in this convention, i.e. `1` or `-1`
+    fn rotation_sign<T: ConstSigned>() -> T{
+        if Self::Y_UP { T::ONE } else { T::NEG_ONE }
+    }
+    /// Returns the vector turned a quarter turn counter-clockwise
+    ///
+    /// For `YUp` this is the same as `Vector2::normal`.
+    fn normal<T: Neg<Output=T>>(v: Vector2<T>) -> Vector2<T>{
+        let Vector2(x, y) = v;
+        if Self::Y_UP { Vector2(-y, x) } else { Vector2(y, -x) }
+    }
+    /// Rotates the vector counter-clockwise by an angle in radians
+    ///
+    /// For `YUp` this is the same as `Vector2::rotate`.
+    fn rotate<T: Real>(v: Vector2<T>, angle: T) -> Vector2<T>{
+        v.rotate(if Self::Y_UP { angle } else { -angle })
+    }
+    /// Converts a direction from this convention into another
+    fn convert<C: Convention, T: Neg<Output=T>>(v: Vector2<T>) -> Vector2<T>{
+        if Self::Y_UP == C::Y_UP { v } else { Vector2(v.0, -v.1) }
+    }
+    /// Converts a position from this convention into another,
+    /// where `height` is the distance between the top and bottom edges
+    ///
+    /// The origin stays on the same vertical edge but moves to the opposite horizontal edge.
+    fn convert_point<C: Convention, T: Sub<Output=T>>(p: Vector2<T>, height: T) -> Vector2<T>{
+        if Self::Y_UP == C::Y_UP { p } else { Vector2(p.0, height - p.1) }
+    }
+}
+
+/// The y-axis points up, as in mathematics and most world spaces
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct YUp;
+
+/// The y-axis points down, as in most screen and image spaces
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct YDown;
+
+impl Convention for YUp{
+    const Y_UP: bool = true;
+}
+
+impl Convention for YDown{
+    const Y_UP: bool = false;
+}
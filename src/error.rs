@@ -0,0 +1,62 @@
+#[cfg(feature="std")]
+use std::error::Error;
+use core::fmt;
+
+/// The reason a vector could not be normalised
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NormaliseError{
+    /// The vector has a length of zero and so has no direction
+    ZeroLength,
+    /// A component of the vector or its length is infinite or `NaN`
+    NonFinite,
+}
+
+impl fmt::Display for NormaliseError{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result{
+        match *self {
+            NormaliseError::ZeroLength => f.write_str("cannot normalise a vector of length zero"),
+            NormaliseError::NonFinite => f.write_str("cannot normalise a vector that is not finite"),
+        }
+    }
+}
+
+#[cfg(feature="std")]
+impl Error for NormaliseError{}
+
+/// The reason a string could not be parsed as a vector
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseVectorError<E>{
+    /// An opening bracket was not matched by the right closing bracket or vice versa
+    UnmatchedBracket,
+    /// There were not exactly two comma-separated components; holds how many were found
+    ComponentCount(usize),
+    /// A component could not be parsed
+    InvalidComponent{
+        /// The index of the component, `0` for x and `1` for y
+        index: usize,
+        /// The error from parsing the component
+        error: E,
+    },
+}
+
+impl<E: fmt::Display> fmt::Display for ParseVectorError<E>{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result{
+        match *self {
+            ParseVectorError::UnmatchedBracket => f.write_str("unmatched bracket in vector"),
+            ParseVectorError::ComponentCount(n) => write!(f, "expected 2 comma-separated components in vector, found {}", n),
+            ParseVectorError::InvalidComponent{index, ref error} => {
+                write!(f, "invalid {} component in vector: {}", if index == 0 { "x" } else { "y" }, error)
+            }
+        }
+    }
+}
+
+#[cfg(feature="std")]
+impl<E: Error + 'static> Error for ParseVectorError<E>{
+    fn source(&self) -> Option<&(dyn Error + 'static)>{
+        match *self {
+            ParseVectorError::InvalidComponent{ref error, ..} => Some(error),
+            _ => None,
+        }
+    }
+}
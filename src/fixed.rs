@@ -0,0 +1,385 @@
+//! Deterministic fixed-point scalars
+//!
+//! All operations, including `sqrt`, `sin_cos` and `atan2`, are computed with integer
+//! arithmetic only, so results are bit-exact on every platform and compiler.
+//! This makes `Vector2<Fixed16>` and `Vector2<Fixed32>` suitable for lockstep simulations.
+//!
+//! Fixed-point numbers have no `NaN`, so normalising the zero vector panics
+//! with a division by zero just like integer division does.
+//! Arithmetic that overflows panics as well, in release builds too,
+//! so an overflow can never silently desynchronise a simulation.
+
+use super::Real;
+use super::consts::{ConstScalar, ConstSigned};
+
+use num_traits::{Float, Zero, One, ToPrimitive};
+
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg};
+
+// Trigonometry is computed in Q32.32 regardless of the scalar type
+const PI_Q32: i64 = 13_493_037_705;
+const FRAC_PI_2_Q32: i64 = 6_746_518_852;
+const TAU_Q32: i64 = 26_986_075_409;
+// The reciprocal of the CORDIC gain after 32 iterations
+const CORDIC_K_Q32: i64 = 2_608_131_496;
+// atan(2^-i) for i in 0..32
+const ATAN_TABLE_Q32: [i64; 32] = [
+    3_373_259_426, 1_991_351_318, 1_052_175_346, 534_100_635,
+    268_086_748, 134_174_063, 67_103_403, 33_553_749,
+    16_777_131, 8_388_597, 4_194_303, 2_097_152,
+    1_048_576, 524_288, 262_144, 131_072,
+    65_536, 32_768, 16_384, 8_192,
+    4_096, 2_048, 1_024, 512,
+    256, 128, 64, 32,
+    16, 8, 4, 2,
+];
+
+/// Returns the sine and cosine of a Q32.32 angle in Q32.32
+fn cordic_sin_cos(angle: i64) -> (i64, i64){
+    // Reduce to (-π, π] and then to [-π/2, π/2], which CORDIC converges for
+    let mut z = angle.rem_euclid(TAU_Q32);
+    if z > PI_Q32 {
+        z -= TAU_Q32;
+    }
+    let flip = if z > FRAC_PI_2_Q32 {
+        z -= PI_Q32;
+        true
+    } else if z < -FRAC_PI_2_Q32 {
+        z += PI_Q32;
+        true
+    } else {
+        false
+    };
+
+    let (mut x, mut y) = (CORDIC_K_Q32, 0);
+    for (i, &atan) in ATAN_TABLE_Q32.iter().enumerate() {
+        let (dx, dy) = (y >> i, x >> i);
+        if z >= 0 {
+            x -= dx;
+            y += dy;
+            z -= atan;
+        } else {
+            x += dx;
+            y -= dy;
+            z += atan;
+        }
+    }
+
+    if flip {
+        (-y, -x)
+    } else {
+        (y, x)
+    }
+}
+
+/// Returns the angle of the point `(x, y)` in Q32.32
+///
+/// The inputs only need to share a scale.
+fn cordic_atan2(y: i64, x: i64) -> i64{
+    if y == 0 {
+        return if x < 0 { PI_Q32 } else { 0 };
+    }
+    if x == 0 {
+        return if y > 0 { FRAC_PI_2_Q32 } else { -FRAC_PI_2_Q32 };
+    }
+
+    let (mut x, mut y) = (x as i128, y as i128);
+    let upper = y > 0;
+    // Rotate into the right half-plane
+    let mut z = if x < 0 {
+        x = -x;
+        y = -y;
+        if upper { PI_Q32 } else { -PI_Q32 }
+    } else {
+        0
+    };
+    // Scale up so small inputs keep their precision
+    let shift = (x.abs().max(y.abs())).leading_zeros().saturating_sub(4);
+    x <<= shift;
+    y <<= shift;
+
+    for (i, &atan) in ATAN_TABLE_Q32.iter().enumerate() {
+        let (dx, dy) = (y >> i, x >> i);
+        if y > 0 {
+            x += dx;
+            y -= dy;
+            z += atan;
+        } else {
+            x -= dx;
+            y += dy;
+            z -= atan;
+        }
+    }
+
+    // Keep the result in the half-plane of the input
+    if upper {
+        z.clamp(0, PI_Q32)
+    } else {
+        z.clamp(-PI_Q32, 0)
+    }
+}
+
+/// Returns the integer square root of `n` rounded down
+fn isqrt(mut n: u128) -> u128{
+    let mut res = 0;
+    let mut bit = 1 << 126;
+    while bit > n {
+        bit >>= 2;
+    }
+    while bit != 0 {
+        if n >= res + bit {
+            n -= res + bit;
+            res = (res >> 1) + bit;
+        } else {
+            res >>= 1;
+        }
+        bit >>= 2;
+    }
+    res
+}
+
+macro_rules! fixed {
+    ($(#[$attr:meta])* $F:ident($bits:ty, $wide:ty, $frac:expr)) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        #[cfg_attr(feature="serde_derive", derive(Serialize, Deserialize))]
+        pub struct $F($bits);
+
+        impl $F{
+            /// The number of fractional bits
+            pub const FRAC_BITS: u32 = $frac;
+            /// Zero
+            pub const ZERO: $F = $F(0);
+            /// One
+            pub const ONE: $F = $F(1 << $frac);
+            /// The smallest representable value
+            pub const MIN: $F = $F(<$bits>::MIN);
+            /// The largest representable value
+            pub const MAX: $F = $F(<$bits>::MAX);
+            /// Archimedes’ constant (π)
+            pub const PI: $F = $F::from_q32(PI_Q32);
+            /// π/2
+            pub const FRAC_PI_2: $F = $F::from_q32(FRAC_PI_2_Q32);
+            /// The full circle constant (τ = 2π)
+            pub const TAU: $F = $F::from_q32(TAU_Q32);
+
+            /// Creates a number from its raw bit representation
+            pub const fn from_bits(bits: $bits) -> Self{
+                $F(bits)
+            }
+            /// Returns the raw bit representation
+            pub const fn to_bits(self) -> $bits{
+                self.0
+            }
+            /// Creates a number from an integer
+            pub const fn from_int(n: $bits) -> Self{
+                $F(n << $frac)
+            }
+            /// Creates the nearest number to a floating point value
+            pub fn from_f64(f: f64) -> Self{
+                $F(Float::round(f * (1u64 << $frac) as f64) as $bits)
+            }
+            /// Returns the value as a floating point number
+            pub fn to_f64(self) -> f64{
+                self.0 as f64 / (1u64 << $frac) as f64
+            }
+            /// Returns the value as a floating point number
+            pub fn to_f32(self) -> f32{
+                self.to_f64() as f32
+            }
+
+            const fn from_q32(q: i64) -> Self{
+                let shift = 32 - $frac;
+                $F(((q + ((1 << shift) >> 1)) >> shift) as $bits)
+            }
+            fn to_q32(self) -> i64{
+                (self.0 as i64) << (32 - $frac)
+            }
+
+            /// Returns the absolute value
+            pub fn abs(self) -> Self{
+                $F(self.0.abs())
+            }
+            /// Returns the square root rounded down
+            ///
+            /// Negative numbers have no square root, so zero is returned instead.
+            pub fn sqrt(self) -> Self{
+                if self.0 <= 0 {
+                    return $F::ZERO;
+                }
+                $F(isqrt((self.0 as u128) << $frac) as $bits)
+            }
+            /// Returns the sine and cosine of an angle in radians
+            pub fn sin_cos(self) -> (Self, Self){
+                let (sin, cos) = cordic_sin_cos(self.to_q32());
+                ($F::from_q32(sin), $F::from_q32(cos))
+            }
+            /// Returns the sine of an angle in radians
+            pub fn sin(self) -> Self{
+                self.sin_cos().0
+            }
+            /// Returns the cosine of an angle in radians
+            pub fn cos(self) -> Self{
+                self.sin_cos().1
+            }
+            /// Returns the four quadrant arctangent of `self` (y) and `other` (x) in radians
+            pub fn atan2(self, other: Self) -> Self{
+                $F::from_q32(cordic_atan2(self.0 as i64, other.0 as i64))
+            }
+        }
+
+        impl Real for $F{
+            fn sqrt(self) -> Self{
+                $F::sqrt(self)
+            }
+            fn sin_cos(self) -> (Self, Self){
+                $F::sin_cos(self)
+            }
+            fn atan2(self, other: Self) -> Self{
+                $F::atan2(self, other)
+            }
+            fn magnitude(x: Self, y: Self) -> Self{
+                Self::magnitude_n(&[x, y])
+            }
+            fn magnitude_n(components: &[Self]) -> Self{
+                // Squares are summed in 128 bits so large components don't overflow
+                let sum = components.iter()
+                    .fold(0u128, |sum, c| sum.saturating_add((c.0 as i128 * c.0 as i128) as u128));
+                $F(isqrt(sum).min(<$bits>::MAX as u128) as $bits)
+            }
+            fn is_nan(self) -> bool{
+                false
+            }
+            fn is_infinite(self) -> bool{
+                false
+            }
+            fn is_finite(self) -> bool{
+                true
+            }
+            fn is_normal(self) -> bool{
+                self.0 != 0
+            }
+        }
+
+        impl Add for $F{
+            type Output = $F;
+
+            fn add(self, rhs: Self) -> Self{
+                $F(self.0.checked_add(rhs.0).expect("attempt to add with overflow"))
+            }
+        }
+
+        impl Sub for $F{
+            type Output = $F;
+
+            fn sub(self, rhs: Self) -> Self{
+                $F(self.0.checked_sub(rhs.0).expect("attempt to subtract with overflow"))
+            }
+        }
+
+        impl Mul for $F{
+            type Output = $F;
+
+            fn mul(self, rhs: Self) -> Self{
+                let product = (self.0 as $wide * rhs.0 as $wide) >> $frac;
+                $F(<$bits>::try_from(product).expect("attempt to multiply with overflow"))
+            }
+        }
+
+        impl Div for $F{
+            type Output = $F;
+
+            fn div(self, rhs: Self) -> Self{
+                let quotient = ((self.0 as $wide) << $frac) / rhs.0 as $wide;
+                $F(<$bits>::try_from(quotient).expect("attempt to divide with overflow"))
+            }
+        }
+
+        impl Neg for $F{
+            type Output = $F;
+
+            fn neg(self) -> Self{
+                $F(self.0.checked_neg().expect("attempt to negate with overflow"))
+            }
+        }
+
+        impl AddAssign for $F{
+            fn add_assign(&mut self, rhs: Self){
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for $F{
+            fn sub_assign(&mut self, rhs: Self){
+                *self = *self - rhs;
+            }
+        }
+
+        impl MulAssign for $F{
+            fn mul_assign(&mut self, rhs: Self){
+                *self = *self * rhs;
+            }
+        }
+
+        impl DivAssign for $F{
+            fn div_assign(&mut self, rhs: Self){
+                *self = *self / rhs;
+            }
+        }
+
+        impl ConstScalar for $F{
+            const ZERO: $F = $F::ZERO;
+            const ONE: $F = $F::ONE;
+            const MIN: $F = $F::MIN;
+            const MAX: $F = $F::MAX;
+        }
+
+        impl ConstSigned for $F{
+            const NEG_ONE: $F = $F::from_int(-1);
+        }
+
+        impl Zero for $F{
+            fn zero() -> Self{
+                $F::ZERO
+            }
+            fn is_zero(&self) -> bool{
+                self.0 == 0
+            }
+        }
+
+        impl One for $F{
+            fn one() -> Self{
+                $F::ONE
+            }
+        }
+
+        impl ToPrimitive for $F{
+            fn to_i64(&self) -> Option<i64>{
+                Some((self.0 / (1 << $frac)) as i64)
+            }
+            fn to_u64(&self) -> Option<u64>{
+                (self.0 / (1 << $frac)).to_u64()
+            }
+            fn to_f64(&self) -> Option<f64>{
+                Some($F::to_f64(*self))
+            }
+        }
+
+        impl fmt::Display for $F{
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result{
+                fmt::Display::fmt(&$F::to_f64(*self), f)
+            }
+        }
+    };
+}
+
+fixed!{
+    /// Fixed-point number in Q16.16 format, i.e. 16 integer bits and 16 fractional bits
+    Fixed16(i32, i64, 16)
+}
+fixed!{
+    /// Fixed-point number in Q32.32 format, i.e. 32 integer bits and 32 fractional bits
+    Fixed32(i64, i128, 32)
+}
@@ -0,0 +1,122 @@
+use super::{Vector2, ParseVectorError};
+
+use num_traits::{Float, ToPrimitive};
+
+use core::fmt;
+use core::str::FromStr;
+
+/// Formats the vector as `(x, y)`, passing flags such as precision and width on to each component
+///
+/// The alternate flag (`{:#}`) adds the length and direction for debugging,
+/// e.g. `(3, 4) [length 5, angle 0.9272952180016122]`.
+/// They are computed with `f64` from how the components display, so they are left out
+/// for scalars that don't display as decimal numbers. `debug_display` always includes them
+/// for scalars that implement `ToPrimitive`.
+impl<T: fmt::Display> fmt::Display for Vector2<T>{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result{
+        write_components(self, f)?;
+        if f.alternate() {
+            if let (Some(x), Some(y)) = (displayed_value(&self.0), displayed_value(&self.1)) {
+                write_length_and_angle(x, y, f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Adapter that displays a vector followed by its length and direction, see `Vector2::debug_display`
+#[derive(Copy, Clone, Debug)]
+pub struct DebugDisplay<'a, T: 'a>(&'a Vector2<T>);
+
+impl<T> Vector2<T>{
+    /// Returns an adapter that displays the vector together with its length and direction,
+    /// e.g. `(3, 4) [length 5, angle 0.9272952180016122]`
+    ///
+    /// The length and angle are computed with `f64` and use the same precision as the components.
+    pub fn debug_display(&self) -> DebugDisplay<'_, T>{
+        DebugDisplay(self)
+    }
+}
+
+impl<'a, T: fmt::Display + ToPrimitive> fmt::Display for DebugDisplay<'a, T>{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result{
+        write_components(self.0, f)?;
+        let x = (self.0).0.to_f64().unwrap_or(f64::NAN);
+        let y = (self.0).1.to_f64().unwrap_or(f64::NAN);
+        write_length_and_angle(x, y, f)
+    }
+}
+
+fn write_components<T: fmt::Display>(v: &Vector2<T>, f: &mut fmt::Formatter) -> fmt::Result{
+    f.write_str("(")?;
+    fmt::Display::fmt(&v.0, f)?;
+    f.write_str(", ")?;
+    fmt::Display::fmt(&v.1, f)?;
+    f.write_str(")")
+}
+
+fn write_length_and_angle(x: f64, y: f64, f: &mut fmt::Formatter) -> fmt::Result{
+    let (length, angle) = (Float::hypot(x, y), Float::atan2(y, x));
+    match f.precision() {
+        Some(p) => write!(f, " [length {:.*}, angle {:.*}]", p, length, p, angle),
+        None => write!(f, " [length {}, angle {}]", length, angle),
+    }
+}
+
+// Collects formatted text on the stack, failing once it is full
+struct Buffer{
+    bytes: [u8; 512],
+    len: usize,
+}
+
+impl fmt::Write for Buffer{
+    fn write_str(&mut self, s: &str) -> fmt::Result{
+        let end = self.len + s.len();
+        if end > self.bytes.len() {
+            return Err(fmt::Error);
+        }
+        self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+// Reads back the number a scalar displays as, which is exact for the
+// primitives and fixed-point types as their `Display` round-trips
+fn displayed_value<T: fmt::Display>(t: &T) -> Option<f64>{
+    use core::fmt::Write;
+
+    let mut buffer = Buffer{bytes: [0; 512], len: 0};
+    write!(buffer, "{}", t).ok()?;
+    core::str::from_utf8(&buffer.bytes[..buffer.len]).ok()?.parse().ok()
+}
+
+/// Parses a vector written as `(x, y)`, `[x, y]` or `x, y`
+///
+/// Whitespace around the brackets and components is ignored.
+impl<T: FromStr> FromStr for Vector2<T>{
+    type Err = ParseVectorError<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>{
+        let s = s.trim();
+        let inner = match (s.chars().next(), s.chars().last()) {
+            (Some('('), Some(')')) | (Some('['), Some(']')) if s.len() >= 2 => &s[1..s.len()-1],
+            (Some('('), _) | (Some('['), _) | (_, Some(')')) | (_, Some(']')) => {
+                return Err(ParseVectorError::UnmatchedBracket)
+            }
+            _ => s,
+        };
+        if inner.trim().is_empty() {
+            return Err(ParseVectorError::ComponentCount(0));
+        }
+
+        let count = inner.split(',').count();
+        if count != 2 {
+            return Err(ParseVectorError::ComponentCount(count));
+        }
+        let mut components = inner.split(',').map(str::trim);
+        let mut parse = |index: usize| components.next().unwrap_or("").parse()
+            .map_err(|error| ParseVectorError::InvalidComponent{index, error});
+        Ok(Vector2(parse(0)?, parse(1)?))
+    }
+}
@@ -0,0 +1,133 @@
+use super::Vector2;
+
+use num_traits::{CheckedAdd, CheckedSub, CheckedMul, CheckedDiv, WrappingAdd, WrappingSub, WrappingMul};
+use num_traits::{SaturatingAdd, SaturatingSub, SaturatingMul, Euclid};
+
+/// Lengths of integer vectors computed in a wider type so they cannot overflow
+///
+/// This is a trait rather than inherent methods so the names do not clash with
+/// the floating point `length_squared`.
+pub trait IntegerVector{
+    /// The unsigned type wide enough to hold the lengths
+    type Wide;
+
+    /// Returns the magnitude/length of the vector squared
+    fn length_squared(self) -> Self::Wide;
+    /// Returns the manhattan length of the vector i.e. the sum of the absolute components
+    fn manhattan_length(self) -> Self::Wide;
+}
+
+macro_rules! impl_integer_vector {
+    (signed $($t:ty => $w:ty),*) => {$(
+        impl IntegerVector for Vector2<$t>{
+            type Wide = $w;
+
+            fn length_squared(self) -> $w{
+                let (x, y) = (self.0.unsigned_abs() as $w, self.1.unsigned_abs() as $w);
+                x * x + y * y
+            }
+            fn manhattan_length(self) -> $w{
+                self.0.unsigned_abs() as $w + self.1.unsigned_abs() as $w
+            }
+        }
+    )*};
+    (unsigned $($t:ty => $w:ty),*) => {$(
+        impl IntegerVector for Vector2<$t>{
+            type Wide = $w;
+
+            fn length_squared(self) -> $w{
+                let (x, y) = (self.0 as $w, self.1 as $w);
+                x * x + y * y
+            }
+            fn manhattan_length(self) -> $w{
+                self.0 as $w + self.1 as $w
+            }
+        }
+    )*};
+}
+impl_integer_vector!{signed i8 => u16, i16 => u32, i32 => u64, i64 => u128}
+impl_integer_vector!{unsigned u8 => u32, u16 => u64, u32 => u128}
+
+impl<T: CheckedAdd> Vector2<T>{
+    /// Adds two vectors, returning `None` if either component overflows
+    pub fn checked_add(self, rhs: Self) -> Option<Self>{
+        Some(Vector2(self.0.checked_add(&rhs.0)?, self.1.checked_add(&rhs.1)?))
+    }
+}
+
+impl<T: CheckedSub> Vector2<T>{
+    /// Subtracts two vectors, returning `None` if either component overflows
+    pub fn checked_sub(self, rhs: Self) -> Option<Self>{
+        Some(Vector2(self.0.checked_sub(&rhs.0)?, self.1.checked_sub(&rhs.1)?))
+    }
+}
+
+impl<T: CheckedMul> Vector2<T>{
+    /// Multiplies the vector by a scalar, returning `None` if either component overflows
+    pub fn checked_mul(self, rhs: T) -> Option<Self>{
+        Some(Vector2(self.0.checked_mul(&rhs)?, self.1.checked_mul(&rhs)?))
+    }
+}
+
+impl<T: CheckedDiv> Vector2<T>{
+    /// Divides the vector by a scalar, returning `None` on division by zero or overflow
+    pub fn checked_div(self, rhs: T) -> Option<Self>{
+        Some(Vector2(self.0.checked_div(&rhs)?, self.1.checked_div(&rhs)?))
+    }
+}
+
+impl<T: WrappingAdd> Vector2<T>{
+    /// Adds two vectors, wrapping around at the boundary of the type
+    pub fn wrapping_add(self, rhs: Self) -> Self{
+        Vector2(self.0.wrapping_add(&rhs.0), self.1.wrapping_add(&rhs.1))
+    }
+}
+
+impl<T: WrappingSub> Vector2<T>{
+    /// Subtracts two vectors, wrapping around at the boundary of the type
+    pub fn wrapping_sub(self, rhs: Self) -> Self{
+        Vector2(self.0.wrapping_sub(&rhs.0), self.1.wrapping_sub(&rhs.1))
+    }
+}
+
+impl<T: WrappingMul> Vector2<T>{
+    /// Multiplies the vector by a scalar, wrapping around at the boundary of the type
+    pub fn wrapping_mul(self, rhs: T) -> Self{
+        Vector2(self.0.wrapping_mul(&rhs), self.1.wrapping_mul(&rhs))
+    }
+}
+
+impl<T: SaturatingAdd> Vector2<T>{
+    /// Adds two vectors, saturating at the numeric bounds of the type
+    pub fn saturating_add(self, rhs: Self) -> Self{
+        Vector2(self.0.saturating_add(&rhs.0), self.1.saturating_add(&rhs.1))
+    }
+}
+
+impl<T: SaturatingSub> Vector2<T>{
+    /// Subtracts two vectors, saturating at the numeric bounds of the type
+    pub fn saturating_sub(self, rhs: Self) -> Self{
+        Vector2(self.0.saturating_sub(&rhs.0), self.1.saturating_sub(&rhs.1))
+    }
+}
+
+impl<T: SaturatingMul> Vector2<T>{
+    /// Multiplies the vector by a scalar, saturating at the numeric bounds of the type
+    pub fn saturating_mul(self, rhs: T) -> Self{
+        Vector2(self.0.saturating_mul(&rhs), self.1.saturating_mul(&rhs))
+    }
+}
+
+impl<T: Euclid> Vector2<T>{
+    /// Divides the vector by a scalar using euclidean division
+    ///
+    /// Unlike `/` this rounds towards negative infinity for positive divisors,
+    /// so e.g. tile coordinate `-1` lies in chunk `-1` rather than chunk `0`.
+    pub fn div_euclid(self, rhs: T) -> Self{
+        Vector2(self.0.div_euclid(&rhs), self.1.div_euclid(&rhs))
+    }
+    /// Returns the least non-negative remainder of each component divided by a scalar
+    pub fn rem_euclid(self, rhs: T) -> Self{
+        Vector2(self.0.rem_euclid(&rhs), self.1.rem_euclid(&rhs))
+    }
+}
@@ -0,0 +1,95 @@
+//! Interpolation between vectors
+//!
+//! The interpolation methods themselves are found on `Vector2`,
+//! this module holds the easing functions they use.
+
+use super::Vector2;
+
+use num_traits::Float;
+
+/// Restricts `t` to the range `[0, 1]`
+fn saturate<T: Float>(t: T) -> T{
+    t.max(T::zero()).min(T::one())
+}
+
+/// Eases `t` in the range `[0, 1]` so it starts and ends with a speed of zero
+///
+/// Defined as 3t² - 2t³ with `t` clamped to `[0, 1]`
+pub fn smoothstep<T: Float>(t: T) -> T{
+    let t = saturate(t);
+    let two = T::one() + T::one();
+    t * t * (two + T::one() - two * t)
+}
+
+/// Eases `t` in the range `[0, 1]` so it starts and ends with a speed and acceleration of zero
+///
+/// Defined as 6t⁵ - 15t⁴ + 10t³ with `t` clamped to `[0, 1]`
+pub fn smootherstep<T: Float>(t: T) -> T{
+    let t = saturate(t);
+    let six = T::from(6).unwrap();
+    let fifteen = T::from(15).unwrap();
+    let ten = T::from(10).unwrap();
+    t * t * t * (t * (t * six - fifteen) + ten)
+}
+
+impl<T: Float> Vector2<T>{
+    /// Linearly interpolates between two vectors with `t` clamped to `[0, 1]`
+    ///
+    /// `t = 0` gives `self` and `t = 1` gives `other`.
+    pub fn lerp(self, other: Self, t: T) -> Self{
+        self.lerp_unclamped(other, saturate(t))
+    }
+    /// Linearly interpolates between two vectors, extrapolating for `t` outside `[0, 1]`
+    pub fn lerp_unclamped(self, other: Self, t: T) -> Self{
+        self + (other - self) * t
+    }
+    /// Returns the parameter `t` of the closest point to `point` on the line from `self` to `end`
+    ///
+    /// This is the inverse of `lerp_unclamped`, so `t` is `0` at `self` and `1` at `end`.
+    /// Returns zero if `self` and `end` are the same.
+    pub fn inverse_lerp(self, end: Self, point: Self) -> T{
+        let segment = end - self;
+        let length_squared = segment.length_squared();
+        if length_squared.is_zero() {
+            T::zero()
+        } else {
+            (point - self).dot(segment) / length_squared
+        }
+    }
+    /// Linearly interpolates between two directions and normalises the result
+    ///
+    /// Cheaper than `slerp`, but the angle does not change at a constant speed.
+    /// Where the interpolated vector is zero, e.g. halfway between opposite directions,
+    /// the direction of the nearer of the two vectors is returned instead.
+    pub fn nlerp(self, other: Self, t: T) -> Self{
+        let nearer = if t + t < T::one() { self } else { other };
+        self.lerp(other, t).normalise_or(nearer.normalise_or_zero())
+    }
+    /// Interpolates between two directions at a constant angular speed taking the shortest way
+    ///
+    /// The length is interpolated linearly, so unit vectors stay unit vectors.
+    pub fn slerp(self, other: Self, t: T) -> Self{
+        let t = saturate(t);
+        let angle = self.det(other).atan2(self.dot(other));
+        let length = self.length() + (other.length() - self.length()) * t;
+        Vector2::unit_vector(self.direction() + angle * t) * length
+    }
+    /// Moves towards `target` by at most `max_delta` without overshooting it
+    pub fn move_towards(self, target: Self, max_delta: T) -> Self{
+        let delta = target - self;
+        let distance = delta.length();
+        if distance <= max_delta || distance.is_zero() {
+            target
+        } else {
+            self + delta * (max_delta / distance)
+        }
+    }
+    /// Interpolates between two vectors, easing in and out with `smoothstep`
+    pub fn smooth_lerp(self, other: Self, t: T) -> Self{
+        self.lerp_unclamped(other, smoothstep(t))
+    }
+    /// Interpolates between two vectors, easing in and out with `smootherstep`
+    pub fn smoother_lerp(self, other: Self, t: T) -> Self{
+        self.lerp_unclamped(other, smootherstep(t))
+    }
+}
@@ -1,8 +1,15 @@
 #![warn(missing_docs)]
+#![no_std]
+#![cfg_attr(feature="simd", feature(portable_simd))]
 //! Simple and generic implementation of 2D vectors
 //!
 //! Intended for use in 2D game engines
+//!
+//! The crate is `no_std` when the default `std` feature is disabled.
+//! The floating point functions then come from `libm`, so the `libm` feature has to be enabled instead.
 
+#[cfg(feature="std")]
+extern crate std;
 extern crate num_traits;
 #[cfg(feature="rustc-serialize")]
 extern crate rustc_serialize;
@@ -11,7 +18,8 @@ extern crate rustc_serialize;
 #[cfg_attr(feature="serde_derive", macro_use)]
 extern crate serde_derive;
 
-use num_traits::Float;
+#[cfg(not(any(feature="std", feature="libm")))]
+compile_error!("either the `std` or the `libm` feature has to be enabled");
 
 /// Representation of a mathematical vector e.g. a position or velocity
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
@@ -19,8 +27,50 @@ use num_traits::Float;
 #[cfg_attr(feature="serde_derive", derive(Serialize, Deserialize))]
 pub struct Vector2<T>(pub T, pub T);
 
-use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg};
-use std::convert::From;
+use core::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg};
+use core::convert::From;
+
+#[macro_use]
+mod macros;
+pub mod aggregate;
+mod angle;
+mod approx;
+pub mod batch;
+mod cast;
+mod componentwise;
+pub mod convention;
+mod error;
+pub mod fixed;
+mod format;
+mod integer;
+pub mod interpolate;
+mod matrix;
+mod point;
+mod polar;
+mod real;
+mod ref_ops;
+mod rotation;
+mod swizzle;
+mod total;
+mod transform;
+mod vector3;
+mod vector4;
+pub use angle::{Rad, Deg};
+pub use approx::ApproxEq;
+#[doc(hidden)]
+pub use approx::{__relative_eq, __ulps_eq};
+pub use error::{NormaliseError, ParseVectorError};
+pub use format::DebugDisplay;
+pub use integer::IntegerVector;
+pub use matrix::{Matrix2, Matrix3};
+pub use point::Point2;
+pub use polar::{Polar, LogPolar};
+pub use real::Real;
+pub use rotation::Rotation2;
+pub use total::{TotalOrder, TotalVector2};
+pub use transform::Transform2;
+pub use vector3::Vector3;
+pub use vector4::Vector4;
 
 /// Constants for common vectors
 pub mod consts{
@@ -47,9 +97,109 @@ pub mod consts{
     pub const RIGHT_F64: Vector2<f64> = Vector2(1., 0.);
     /// A unit vector pointing to the left
     pub const LEFT_F64: Vector2<f64> = Vector2(-1., 0.);
+
+    /// Scalars with constant zero, one and bounds
+    ///
+    /// Implementing this for a scalar type gives `Vector2` constants such as `Vector2::ZERO`.
+    pub trait ConstScalar: Sized{
+        /// Zero
+        const ZERO: Self;
+        /// One
+        const ONE: Self;
+        /// The smallest value
+        const MIN: Self;
+        /// The largest value
+        const MAX: Self;
+    }
+
+    /// Scalars with a constant negative one
+    pub trait ConstSigned: ConstScalar{
+        /// Negative one
+        const NEG_ONE: Self;
+    }
+
+    /// Scalars with constant `NaN` and infinities
+    pub trait ConstFloat: ConstSigned{
+        /// Not a number
+        const NAN: Self;
+        /// Positive infinity
+        const INFINITY: Self;
+        /// Negative infinity
+        const NEG_INFINITY: Self;
+    }
+
+    macro_rules! impl_const_scalar {
+        ($($t:ident: $zero:expr, $one:expr;)*) => {$(
+            impl ConstScalar for $t{
+                const ZERO: $t = $zero;
+                const ONE: $t = $one;
+                const MIN: $t = $t::MIN;
+                const MAX: $t = $t::MAX;
+            }
+        )*};
+    }
+    impl_const_scalar!{
+        i8: 0, 1; i16: 0, 1; i32: 0, 1; i64: 0, 1; i128: 0, 1; isize: 0, 1;
+        u8: 0, 1; u16: 0, 1; u32: 0, 1; u64: 0, 1; u128: 0, 1; usize: 0, 1;
+        f32: 0., 1.; f64: 0., 1.;
+    }
+
+    macro_rules! impl_const_signed {
+        ($($t:ident: $neg_one:expr;)*) => {$(
+            impl ConstSigned for $t{
+                const NEG_ONE: $t = $neg_one;
+            }
+        )*};
+    }
+    impl_const_signed!{
+        i8: -1; i16: -1; i32: -1; i64: -1; i128: -1; isize: -1;
+        f32: -1.; f64: -1.;
+    }
+
+    macro_rules! impl_const_float {
+        ($($t:ident)*) => {$(
+            impl ConstFloat for $t{
+                const NAN: $t = $t::NAN;
+                const INFINITY: $t = $t::INFINITY;
+                const NEG_INFINITY: $t = $t::NEG_INFINITY;
+            }
+        )*};
+    }
+    impl_const_float!{f32 f64}
+}
+
+impl<T: consts::ConstScalar> Vector2<T>{
+    /// The zero vector
+    pub const ZERO: Self = Vector2(T::ZERO, T::ZERO);
+    /// The vector with both components set to one
+    pub const ONE: Self = Vector2(T::ONE, T::ONE);
+    /// A unit vector along the x-axis
+    pub const X: Self = Vector2(T::ONE, T::ZERO);
+    /// A unit vector along the y-axis
+    pub const Y: Self = Vector2(T::ZERO, T::ONE);
+    /// The vector with both components set to the smallest value
+    pub const MIN: Self = Vector2(T::MIN, T::MIN);
+    /// The vector with both components set to the largest value
+    pub const MAX: Self = Vector2(T::MAX, T::MAX);
+}
+
+impl<T: consts::ConstSigned> Vector2<T>{
+    /// A unit vector along the negative x-axis
+    pub const NEG_X: Self = Vector2(T::NEG_ONE, T::ZERO);
+    /// A unit vector along the negative y-axis
+    pub const NEG_Y: Self = Vector2(T::ZERO, T::NEG_ONE);
+}
+
+impl<T: consts::ConstFloat> Vector2<T>{
+    /// The vector with both components set to `NaN`
+    pub const NAN: Self = Vector2(T::NAN, T::NAN);
+    /// The vector with both components set to positive infinity
+    pub const INFINITY: Self = Vector2(T::INFINITY, T::INFINITY);
+    /// The vector with both components set to negative infinity
+    pub const NEG_INFINITY: Self = Vector2(T::NEG_INFINITY, T::NEG_INFINITY);
 }
 
-impl<T: Float> Vector2<T>{
+impl<T: Real> Vector2<T>{
     /// Creates a new unit vector in a specific direction
     pub fn unit_vector(direction: T) -> Self{
         let (y, x) = direction.sin_cos();
@@ -61,12 +211,11 @@ impl<T: Float> Vector2<T>{
     }
     /// Returns the magnitude/length of the vector
     pub fn length(self) -> T{
-        // This is apparently faster than using hypot
-        self.length_squared().sqrt()
+        T::magnitude(self.0, self.1)
     }
     /// Returns the magnitude/length of the vector squared
     pub fn length_squared(self) -> T{
-        self.0.powi(2) + self.1.powi(2)
+        self.0 * self.0 + self.1 * self.1
     }
     /// Returns direction the vector is pointing
     pub fn direction(self) -> T{
@@ -102,24 +251,104 @@ impl<T: Float> Vector2<T>{
     }
 }
 
-macro_rules! impl_for {
-    ($($t:ty)*) => {$(
-        impl Mul<Vector2<$t>> for $t{
-            type Output = Vector2<$t>;
-
-            fn mul(self, rhs: Vector2<$t>) -> Vector2<$t>{
-                Vector2(self * rhs.0, self * rhs.1)
-            }
+impl<T: Real> Vector2<T>{
+    /// Normalises the vector, failing if it has no direction or is not finite
+    pub fn try_normalise(self) -> Result<Self, NormaliseError>{
+        if !self.is_all_finite() {
+            Err(NormaliseError::NonFinite)
+        } else if self.0.is_zero() && self.1.is_zero() {
+            Err(NormaliseError::ZeroLength)
+        } else {
+            Ok(self.scaled_normalise().0)
         }
-        impl Div<Vector2<$t>> for $t{
-            type Output = Vector2<$t>;
-
-            fn div(self, rhs: Vector2<$t>) -> Vector2<$t>{
-                Vector2(self / rhs.0, self / rhs.1)
-            }
+    }
+    /// Normalises the vector or returns `fallback` if it cannot be normalised
+    pub fn normalise_or(self, fallback: Self) -> Self{
+        self.try_normalise().unwrap_or(fallback)
+    }
+    /// Normalises the vector or returns the zero vector if it cannot be normalised
+    pub fn normalise_or_zero(self) -> Self{
+        self.normalise_or(Vector2(T::zero(), T::zero()))
+    }
+    /// Returns the normalised vector together with the original length
+    ///
+    /// The zero vector and vectors that are not finite keep their length,
+    /// but their direction is the same as `normalise` gives.
+    pub fn normalise_and_length(self) -> (Self, T){
+        if !self.is_all_finite() || (self.0.is_zero() && self.1.is_zero()) {
+            let length = self.length();
+            return (self / length, length);
+        }
+        let (unit, negative_length) = self.scaled_normalise();
+        (unit, -negative_length)
+    }
+    // Divides by the largest component first, so the length of finite, non-zero
+    // vectors neither underflows nor overflows along the way.
+    // Magnitudes are kept negative since `-MIN` overflows for fixed-point and integers,
+    // so this returns the negated length.
+    fn scaled_normalise(self) -> (Self, T){
+        let negative_abs = |t: T| if t > T::zero() { -t } else { t };
+        let (x, y) = (negative_abs(self.0), negative_abs(self.1));
+        let scale = if x < y { x } else { y };
+        let scaled = self / scale;
+        let length = scaled.length();
+        (-(scaled / length), scale * length)
+    }
+    /// Returns the signed length of this vector's projection onto another vector
+    ///
+    /// Returns zero if `onto` is the zero vector.
+    pub fn scalar_projection(self, onto: Self) -> T{
+        let length = onto.length();
+        if length.is_zero() {
+            T::zero()
+        } else {
+            self.dot(onto) / length
+        }
+    }
+    /// Returns the projection of this vector onto another vector
+    ///
+    /// Returns the zero vector if `onto` is the zero vector.
+    pub fn project_onto(self, onto: Self) -> Self{
+        let length_squared = onto.length_squared();
+        if length_squared.is_zero() {
+            Vector2(T::zero(), T::zero())
+        } else {
+            onto * (self.dot(onto) / length_squared)
         }
-    )*};
-}impl_for!{f32 f64}
+    }
+    /// Returns the part of this vector that is perpendicular to another vector
+    ///
+    /// Returns the vector itself if `from` is the zero vector.
+    pub fn reject_from(self, from: Self) -> Self{
+        self - self.project_onto(from)
+    }
+    /// Reflects the vector off a surface with the given unit normal
+    ///
+    /// The normal of a surface along `edge` can be found with `edge.normal().normalise()`.
+    pub fn reflect(self, normal: Self) -> Self{
+        self - normal * ((T::one() + T::one()) * self.dot(normal))
+    }
+    /// Refracts this unit vector through a surface with the given unit normal
+    ///
+    /// `eta` is the ratio of the refractive indices of the two media.
+    /// Returns `None` on total internal reflection.
+    pub fn refract(self, normal: Self, eta: T) -> Option<Self>{
+        let cos = self.dot(normal);
+        let k = T::one() - eta * eta * (T::one() - cos * cos);
+        if k < T::zero() {
+            None
+        } else {
+            Some(self * eta - normal * (eta * cos + k.sqrt()))
+        }
+    }
+    /// Removes the part of the vector going into a surface with the given unit normal,
+    /// so it slides along the surface instead
+    pub fn slide(self, normal: Self) -> Self{
+        self - normal * self.dot(normal)
+    }
+}
+
+impl_scalar_lhs!(Vector2 [0 1]; f32 f64 i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
 
 impl<T> Vector2<T> {
     /// Returns the normal vector (aka. hat vector) of this vector i.e. a perpendicular vector
@@ -212,6 +441,7 @@ impl<T: Neg> Neg for Vector2<T>{
     }
 }
 
+#[allow(clippy::from_over_into)]
 impl<T> Into<[T; 2]> for Vector2<T>{
     #[inline]
     fn into(self) -> [T; 2]{
@@ -226,6 +456,7 @@ impl<T: Copy> From<[T; 2]> for Vector2<T>{
     }
 }
 
+#[allow(clippy::from_over_into)]
 impl<T> Into<(T, T)> for Vector2<T>{
     #[inline]
     fn into(self) -> (T, T){
@@ -0,0 +1,171 @@
+// Implements the component-wise operators and conversions shared by
+// the vector types of every dimension.
+macro_rules! impl_vector_ops {
+    ($V:ident, $n:expr, $tuple:ty, $($f:tt)+) => {
+        impl<T: Add> Add for $V<T>{
+            type Output = $V<T::Output>;
+
+            fn add(self, rhs: Self) -> Self::Output{
+                $V($(self.$f + rhs.$f),+)
+            }
+        }
+
+        impl<T: Sub> Sub for $V<T>{
+            type Output = $V<T::Output>;
+
+            fn sub(self, rhs: Self) -> Self::Output{
+                $V($(self.$f - rhs.$f),+)
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $V<T>{
+            fn add_assign(&mut self, rhs: Self){
+                $(self.$f += rhs.$f;)+
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $V<T>{
+            fn sub_assign(&mut self, rhs: Self){
+                $(self.$f -= rhs.$f;)+
+            }
+        }
+
+        impl<T: MulAssign + Copy> MulAssign<T> for $V<T>{
+            fn mul_assign(&mut self, rhs: T){
+                $(self.$f *= rhs;)+
+            }
+        }
+
+        impl<T: DivAssign + Copy> DivAssign<T> for $V<T>{
+            fn div_assign(&mut self, rhs: T){
+                $(self.$f /= rhs;)+
+            }
+        }
+
+        impl<T: Mul + Copy> Mul<T> for $V<T>{
+            type Output = $V<T::Output>;
+
+            fn mul(self, rhs: T) -> Self::Output{
+                $V($(self.$f * rhs),+)
+            }
+        }
+
+        impl<T: Div + Copy> Div<T> for $V<T>{
+            type Output = $V<T::Output>;
+
+            fn div(self, rhs: T) -> Self::Output{
+                $V($(self.$f / rhs),+)
+            }
+        }
+
+        impl<T: Neg> Neg for $V<T>{
+            type Output = $V<T::Output>;
+
+            fn neg(self) -> Self::Output{
+                $V($(-self.$f),+)
+            }
+        }
+
+        #[allow(clippy::from_over_into)]
+        impl<T> Into<[T; $n]> for $V<T>{
+            #[inline]
+            fn into(self) -> [T; $n]{
+                [$(self.$f),+]
+            }
+        }
+
+        impl<T: Copy> From<[T; $n]> for $V<T>{
+            #[inline]
+            fn from(array: [T; $n]) -> Self{
+                $V($(array[$f]),+)
+            }
+        }
+
+        #[allow(clippy::from_over_into)]
+        impl<T> Into<$tuple> for $V<T>{
+            #[inline]
+            fn into(self) -> $tuple{
+                ($(self.$f),+)
+            }
+        }
+
+        impl<T> From<$tuple> for $V<T>{
+            #[inline]
+            fn from(tuple: $tuple) -> Self{
+                $V($(tuple.$f),+)
+            }
+        }
+    };
+}
+
+// Implements multiplication and division with the scalar on the left,
+// for both owned and borrowed operands
+macro_rules! impl_scalar_lhs {
+    ($V:ident $fields:tt; $($t:ty)*) => {$(
+        impl_scalar_lhs!(@impl $V $fields $t);
+    )*};
+    (@impl $V:ident [$($f:tt)+] $t:ty) => {
+        impl Mul<$V<$t>> for $t{
+            type Output = $V<$t>;
+
+            /// Scales each component by the scalar, the same as `rhs * self`
+            fn mul(self, rhs: $V<$t>) -> $V<$t>{
+                $V($(self * rhs.$f),+)
+            }
+        }
+        impl<'a> Mul<&'a $V<$t>> for $t{
+            type Output = $V<$t>;
+
+            fn mul(self, rhs: &'a $V<$t>) -> $V<$t>{
+                self * *rhs
+            }
+        }
+        impl<'a> Mul<$V<$t>> for &'a $t{
+            type Output = $V<$t>;
+
+            fn mul(self, rhs: $V<$t>) -> $V<$t>{
+                *self * rhs
+            }
+        }
+        impl<'a, 'b> Mul<&'b $V<$t>> for &'a $t{
+            type Output = $V<$t>;
+
+            fn mul(self, rhs: &'b $V<$t>) -> $V<$t>{
+                *self * *rhs
+            }
+        }
+        impl Div<$V<$t>> for $t{
+            type Output = $V<$t>;
+
+            /// Divides the scalar by each component, i.e. scales the reciprocal of each component
+            ///
+            /// `s / v` has the components `s / x` and `s / y`, using integer division for integers,
+            /// so `1 / v` is the component-wise reciprocal. For floats `s / (s / v)` gives back `v`
+            /// only approximately because of rounding.
+            fn div(self, rhs: $V<$t>) -> $V<$t>{
+                $V($(self / rhs.$f),+)
+            }
+        }
+        impl<'a> Div<&'a $V<$t>> for $t{
+            type Output = $V<$t>;
+
+            fn div(self, rhs: &'a $V<$t>) -> $V<$t>{
+                self / *rhs
+            }
+        }
+        impl<'a> Div<$V<$t>> for &'a $t{
+            type Output = $V<$t>;
+
+            fn div(self, rhs: $V<$t>) -> $V<$t>{
+                *self / rhs
+            }
+        }
+        impl<'a, 'b> Div<&'b $V<$t>> for &'a $t{
+            type Output = $V<$t>;
+
+            fn div(self, rhs: &'b $V<$t>) -> $V<$t>{
+                *self / *rhs
+            }
+        }
+    };
+}
@@ -0,0 +1,190 @@
+use super::{Vector2, Vector3, Real};
+
+use num_traits::{Float, Zero, One};
+
+use core::ops::{Add, Sub, Mul, MulAssign};
+
+/// A 2x2 matrix for linear transformations, stored as its two columns
+///
+/// Multiplying a `Vector2` by the matrix gives `x * self.0 + y * self.1`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature="rustc-serialize", derive(RustcDecodable, RustcEncodable))]
+#[cfg_attr(feature="serde_derive", derive(Serialize, Deserialize))]
+pub struct Matrix2<T>(pub Vector2<T>, pub Vector2<T>);
+
+/// A 3x3 matrix for affine transformations in homogeneous coordinates, stored as its three columns
+///
+/// The last column holds the translation, so a `Vector2` can be transformed
+/// as a point, which is translated, or as a direction, which is not.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature="rustc-serialize", derive(RustcDecodable, RustcEncodable))]
+#[cfg_attr(feature="serde_derive", derive(Serialize, Deserialize))]
+pub struct Matrix3<T>(pub Vector3<T>, pub Vector3<T>, pub Vector3<T>);
+
+impl<T: Zero + One> Matrix2<T>{
+    /// The matrix that leaves vectors unchanged
+    pub fn identity() -> Self{
+        Matrix2(Vector2(T::one(), T::zero()), Vector2(T::zero(), T::one()))
+    }
+    /// Creates a matrix scaling each axis by the respective component
+    pub fn scale(scale: Vector2<T>) -> Self{
+        Matrix2(Vector2(scale.0, T::zero()), Vector2(T::zero(), scale.1))
+    }
+    /// Creates a matrix shearing x by `x` times y and y by `y` times x
+    pub fn shear(x: T, y: T) -> Self{
+        Matrix2(Vector2(T::one(), y), Vector2(x, T::one()))
+    }
+}
+
+impl<T: Real> Matrix2<T>{
+    /// Creates a matrix rotating counter-clockwise by an angle in radians
+    pub fn rotation(angle: T) -> Self{
+        let (sin, cos) = angle.sin_cos();
+        Matrix2(Vector2(cos, sin), Vector2(-sin, cos))
+    }
+}
+
+impl<T: Copy> Matrix2<T>{
+    /// Returns the matrix with rows and columns swapped
+    pub fn transpose(self) -> Self{
+        let Matrix2(a, b) = self;
+        Matrix2(Vector2(a.0, b.0), Vector2(a.1, b.1))
+    }
+}
+
+impl<T> Matrix2<T>{
+    /// Returns the determinant of the matrix
+    pub fn determinant(self) -> <<T as Mul>::Output as Sub>::Output
+    where T: Mul, <T as Mul>::Output: Sub{
+        self.0.det(self.1)
+    }
+}
+
+impl<T: Float> Matrix2<T>{
+    /// Returns the inverse of the matrix or `None` if it is singular
+    pub fn inverse(self) -> Option<Self>{
+        let det = self.determinant();
+        if det.is_zero() {
+            return None;
+        }
+        let Matrix2(Vector2(a, b), Vector2(c, d)) = self;
+        Some(Matrix2(Vector2(d, -b) / det, Vector2(-c, a) / det))
+    }
+}
+
+impl<T: Zero + One> Matrix3<T>{
+    /// The matrix that leaves vectors unchanged
+    pub fn identity() -> Self{
+        Matrix2::identity().into()
+    }
+    /// Creates a matrix moving points by `translation`
+    pub fn translation(translation: Vector2<T>) -> Self{
+        Matrix3(
+            Vector3(T::one(), T::zero(), T::zero()),
+            Vector3(T::zero(), T::one(), T::zero()),
+            Vector3(translation.0, translation.1, T::one()),
+        )
+    }
+    /// Creates a matrix scaling each axis by the respective component
+    pub fn scale(scale: Vector2<T>) -> Self{
+        Matrix2::scale(scale).into()
+    }
+    /// Creates a matrix shearing x by `x` times y and y by `y` times x
+    pub fn shear(x: T, y: T) -> Self{
+        Matrix2::shear(x, y).into()
+    }
+}
+
+impl<T: Real + Zero + One> Matrix3<T>{
+    /// Creates a matrix rotating counter-clockwise around the origin by an angle in radians
+    pub fn rotation(angle: T) -> Self{
+        Matrix2::rotation(angle).into()
+    }
+}
+
+impl<T: Copy> Matrix3<T>{
+    /// Returns the matrix with rows and columns swapped
+    pub fn transpose(self) -> Self{
+        let Matrix3(a, b, c) = self;
+        Matrix3(Vector3(a.0, b.0, c.0), Vector3(a.1, b.1, c.1), Vector3(a.2, b.2, c.2))
+    }
+}
+
+impl<T: Float> Matrix3<T>{
+    /// Returns the determinant of the matrix
+    pub fn determinant(self) -> T{
+        self.0.dot(self.1.cross(self.2))
+    }
+    /// Returns the inverse of the matrix or `None` if it is singular
+    pub fn inverse(self) -> Option<Self>{
+        let det = self.determinant();
+        if det.is_zero() {
+            return None;
+        }
+        let Matrix3(a, b, c) = self;
+        // The rows of the inverse are the cross products of the columns
+        Some(Matrix3(b.cross(c) / det, c.cross(a) / det, a.cross(b) / det).transpose())
+    }
+    /// Transforms a vector as a point, so it is affected by translation
+    pub fn transform_point(self, point: Vector2<T>) -> Vector2<T>{
+        let Vector3(x, y, w) = self * point.extend(T::one());
+        Vector2(x / w, y / w)
+    }
+    /// Transforms a vector as a direction, so it is not affected by translation
+    pub fn transform_vector(self, vector: Vector2<T>) -> Vector2<T>{
+        (self * vector.extend(T::zero())).truncate()
+    }
+}
+
+impl<T: Zero + One> From<Matrix2<T>> for Matrix3<T>{
+    #[inline]
+    fn from(m: Matrix2<T>) -> Self{
+        Matrix3(m.0.extend(T::zero()), m.1.extend(T::zero()), Vector3(T::zero(), T::zero(), T::one()))
+    }
+}
+
+impl<T: Mul<Output=T> + Add<Output=T> + Copy> Mul<Vector2<T>> for Matrix2<T>{
+    type Output = Vector2<T>;
+
+    fn mul(self, rhs: Vector2<T>) -> Vector2<T>{
+        self.0 * rhs.0 + self.1 * rhs.1
+    }
+}
+
+impl<T: Mul<Output=T> + Add<Output=T> + Copy> Mul for Matrix2<T>{
+    type Output = Matrix2<T>;
+
+    /// Composes two transformations, so `rhs` is applied first
+    fn mul(self, rhs: Self) -> Self{
+        Matrix2(self * rhs.0, self * rhs.1)
+    }
+}
+
+impl<T: Mul<Output=T> + Add<Output=T> + Copy> MulAssign for Matrix2<T>{
+    fn mul_assign(&mut self, rhs: Self){
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Mul<Output=T> + Add<Output=T> + Copy> Mul<Vector3<T>> for Matrix3<T>{
+    type Output = Vector3<T>;
+
+    fn mul(self, rhs: Vector3<T>) -> Vector3<T>{
+        self.0 * rhs.0 + self.1 * rhs.1 + self.2 * rhs.2
+    }
+}
+
+impl<T: Mul<Output=T> + Add<Output=T> + Copy> Mul for Matrix3<T>{
+    type Output = Matrix3<T>;
+
+    /// Composes two transformations, so `rhs` is applied first
+    fn mul(self, rhs: Self) -> Self{
+        Matrix3(self * rhs.0, self * rhs.1, self * rhs.2)
+    }
+}
+
+impl<T: Mul<Output=T> + Add<Output=T> + Copy> MulAssign for Matrix3<T>{
+    fn mul_assign(&mut self, rhs: Self){
+        *self = *self * rhs;
+    }
+}
@@ -0,0 +1,137 @@
+use super::{Vector2, Real};
+
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+
+/// Representation of a point in space i.e. a position
+///
+/// Unlike `Vector2`, two points cannot be added together.
+/// Subtracting two points gives the `Vector2` between them
+/// and a point can be moved by adding or subtracting a `Vector2`.
+///
+/// ```
+/// use simple_vector2d::{Point2, Vector2};
+///
+/// assert_eq!(Point2(4., 6.) - Point2(1., 2.), Vector2(3., 4.));
+/// assert_eq!(Point2(1., 2.) + Vector2(3., 4.), Point2(4., 6.));
+/// ```
+///
+/// ```compile_fail
+/// use simple_vector2d::Point2;
+///
+/// let _ = Point2(1., 2.) + Point2(3., 4.);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature="rustc-serialize", derive(RustcDecodable, RustcEncodable))]
+#[cfg_attr(feature="serde_derive", derive(Serialize, Deserialize))]
+pub struct Point2<T>(pub T, pub T);
+
+impl<T> Point2<T>{
+    /// Returns the vector from the origin to this point
+    pub fn to_vector(self) -> Vector2<T>{
+        Vector2(self.0, self.1)
+    }
+}
+
+impl<T> Vector2<T>{
+    /// Returns the point this vector points at when placed at the origin
+    pub fn to_point(self) -> Point2<T>{
+        Point2(self.0, self.1)
+    }
+}
+
+impl<T: Real> Point2<T>{
+    /// Returns direction towards another point
+    pub fn direction_to(self, other: Self) -> T{
+        (other-self).direction()
+    }
+    /// Returns the distance betweens two points
+    pub fn distance_to(self, other: Self) -> T{
+        (other-self).length()
+    }
+    /// Returns the distance betweens two points squared
+    pub fn distance_to_squared(self, other: Self) -> T{
+        (other-self).length_squared()
+    }
+}
+
+impl<T: Sub> Sub for Point2<T>{
+    type Output = Vector2<T::Output>;
+
+    fn sub(self, rhs: Self) -> Self::Output{
+        Vector2(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl<T: Add> Add<Vector2<T>> for Point2<T>{
+    type Output = Point2<T::Output>;
+
+    fn add(self, rhs: Vector2<T>) -> Self::Output{
+        Point2(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl<T: Sub> Sub<Vector2<T>> for Point2<T>{
+    type Output = Point2<T::Output>;
+
+    fn sub(self, rhs: Vector2<T>) -> Self::Output{
+        Point2(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl<T: AddAssign> AddAssign<Vector2<T>> for Point2<T>{
+    fn add_assign(&mut self, rhs: Vector2<T>){
+        self.0 += rhs.0;
+        self.1 += rhs.1;
+    }
+}
+
+impl<T: SubAssign> SubAssign<Vector2<T>> for Point2<T>{
+    fn sub_assign(&mut self, rhs: Vector2<T>){
+        self.0 -= rhs.0;
+        self.1 -= rhs.1;
+    }
+}
+
+impl<T> From<Vector2<T>> for Point2<T>{
+    #[inline]
+    fn from(vector: Vector2<T>) -> Self{
+        Point2(vector.0, vector.1)
+    }
+}
+
+impl<T> From<Point2<T>> for Vector2<T>{
+    #[inline]
+    fn from(point: Point2<T>) -> Self{
+        Vector2(point.0, point.1)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl<T> Into<[T; 2]> for Point2<T>{
+    #[inline]
+    fn into(self) -> [T; 2]{
+        [self.0, self.1]
+    }
+}
+
+impl<T: Copy> From<[T; 2]> for Point2<T>{
+    #[inline]
+    fn from(array: [T; 2]) -> Self{
+        Point2(array[0], array[1])
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl<T> Into<(T, T)> for Point2<T>{
+    #[inline]
+    fn into(self) -> (T, T){
+        (self.0, self.1)
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T>{
+    #[inline]
+    fn from(tuple: (T, T)) -> Self{
+        Point2(tuple.0, tuple.1)
+    }
+}
@@ -0,0 +1,159 @@
+use super::Vector2;
+
+use num_traits::Float;
+
+use core::ops::{Add, Sub, Mul, MulAssign, Div, DivAssign};
+
+/// A position or displacement given by its distance from the origin and its direction
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+#[cfg_attr(feature="rustc-serialize", derive(RustcDecodable, RustcEncodable))]
+#[cfg_attr(feature="serde_derive", derive(Serialize, Deserialize))]
+pub struct Polar<T>{
+    /// The distance from the origin
+    pub radius: T,
+    /// The direction in radians
+    pub angle: T,
+}
+
+/// Polar coordinates with the natural logarithm of the radius
+///
+/// Evenly spaced log-radii are evenly spaced in scale,
+/// so adding two log-polar coordinates multiplies the radii and adds the angles.
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+#[cfg_attr(feature="rustc-serialize", derive(RustcDecodable, RustcEncodable))]
+#[cfg_attr(feature="serde_derive", derive(Serialize, Deserialize))]
+pub struct LogPolar<T>{
+    /// The natural logarithm of the distance from the origin
+    pub log_radius: T,
+    /// The direction in radians
+    pub angle: T,
+}
+
+impl<T> Polar<T>{
+    /// Creates new polar coordinates
+    pub fn new(radius: T, angle: T) -> Self{
+        Polar{radius, angle}
+    }
+}
+
+impl<T: Add<Output=T>> Polar<T>{
+    /// Rotates the coordinates counter-clockwise by an angle in radians
+    pub fn rotate(self, angle: T) -> Self{
+        Polar{radius: self.radius, angle: self.angle + angle}
+    }
+}
+
+impl<T> LogPolar<T>{
+    /// Creates new log-polar coordinates
+    pub fn new(log_radius: T, angle: T) -> Self{
+        LogPolar{log_radius, angle}
+    }
+}
+
+impl<T: Float> From<Vector2<T>> for Polar<T>{
+    #[inline]
+    fn from(vector: Vector2<T>) -> Self{
+        Polar{radius: vector.length(), angle: vector.direction()}
+    }
+}
+
+impl<T: Float> From<Polar<T>> for Vector2<T>{
+    #[inline]
+    fn from(polar: Polar<T>) -> Self{
+        Vector2::unit_vector(polar.angle) * polar.radius
+    }
+}
+
+impl<T: Float> From<Polar<T>> for LogPolar<T>{
+    #[inline]
+    fn from(polar: Polar<T>) -> Self{
+        LogPolar{log_radius: polar.radius.ln(), angle: polar.angle}
+    }
+}
+
+impl<T: Float> From<LogPolar<T>> for Polar<T>{
+    #[inline]
+    fn from(log_polar: LogPolar<T>) -> Self{
+        Polar{radius: log_polar.log_radius.exp(), angle: log_polar.angle}
+    }
+}
+
+impl<T: Float> From<Vector2<T>> for LogPolar<T>{
+    #[inline]
+    fn from(vector: Vector2<T>) -> Self{
+        Polar::from(vector).into()
+    }
+}
+
+impl<T: Float> From<LogPolar<T>> for Vector2<T>{
+    #[inline]
+    fn from(log_polar: LogPolar<T>) -> Self{
+        Polar::from(log_polar).into()
+    }
+}
+
+impl<T: Mul<Output=T>> Mul<T> for Polar<T>{
+    type Output = Polar<T>;
+
+    /// Scales the radius
+    fn mul(self, rhs: T) -> Self{
+        Polar{radius: self.radius * rhs, angle: self.angle}
+    }
+}
+
+impl<T: Div<Output=T>> Div<T> for Polar<T>{
+    type Output = Polar<T>;
+
+    /// Scales the radius down
+    fn div(self, rhs: T) -> Self{
+        Polar{radius: self.radius / rhs, angle: self.angle}
+    }
+}
+
+impl<T: Mul<Output=T> + Add<Output=T>> Mul for Polar<T>{
+    type Output = Polar<T>;
+
+    /// Multiplies the radii and adds the angles, like complex multiplication
+    fn mul(self, rhs: Self) -> Self{
+        Polar{radius: self.radius * rhs.radius, angle: self.angle + rhs.angle}
+    }
+}
+
+impl<T: Div<Output=T> + Sub<Output=T>> Div for Polar<T>{
+    type Output = Polar<T>;
+
+    /// Divides the radii and subtracts the angles, like complex division
+    fn div(self, rhs: Self) -> Self{
+        Polar{radius: self.radius / rhs.radius, angle: self.angle - rhs.angle}
+    }
+}
+
+impl<T: MulAssign> MulAssign<T> for Polar<T>{
+    fn mul_assign(&mut self, rhs: T){
+        self.radius *= rhs;
+    }
+}
+
+impl<T: DivAssign> DivAssign<T> for Polar<T>{
+    fn div_assign(&mut self, rhs: T){
+        self.radius /= rhs;
+    }
+}
+
+impl<T: Add> Add for LogPolar<T>{
+    type Output = LogPolar<T::Output>;
+
+    /// Multiplies the radii and adds the angles
+    fn add(self, rhs: Self) -> Self::Output{
+        LogPolar{log_radius: self.log_radius + rhs.log_radius, angle: self.angle + rhs.angle}
+    }
+}
+
+impl<T: Sub> Sub for LogPolar<T>{
+    type Output = LogPolar<T::Output>;
+
+    /// Divides the radii and subtracts the angles
+    fn sub(self, rhs: Self) -> Self::Output{
+        LogPolar{log_radius: self.log_radius - rhs.log_radius, angle: self.angle - rhs.angle}
+    }
+}
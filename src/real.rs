@@ -0,0 +1,63 @@
+use num_traits::{Float, Zero, One};
+
+use core::ops::{Add, Sub, Mul, Div, Neg};
+
+/// Scalars that lengths and directions of vectors can be computed with
+///
+/// Implemented for every `Float` as well as the deterministic fixed-point types in `fixed`.
+pub trait Real: Copy + PartialOrd + Zero + One + Add<Output=Self> + Sub<Output=Self> + Mul<Output=Self> + Div<Output=Self> + Neg<Output=Self>{
+    /// Returns the square root of the number
+    fn sqrt(self) -> Self;
+    /// Returns the sine and cosine of an angle in radians
+    fn sin_cos(self) -> (Self, Self);
+    /// Returns the four quadrant arctangent of `self` (y) and `other` (x) in radians
+    fn atan2(self, other: Self) -> Self;
+    /// Returns the length of the vector `(x, y)`
+    fn magnitude(x: Self, y: Self) -> Self{
+        // This is apparently faster than using hypot
+        (x * x + y * y).sqrt()
+    }
+    /// Returns the length of the vector with any number of components
+    fn magnitude_n(components: &[Self]) -> Self{
+        components.iter().fold(Self::zero(), |sum, &c| sum + c * c).sqrt()
+    }
+    /// Returns `true` if the number is `NaN`
+    fn is_nan(self) -> bool;
+    /// Returns `true` if the number is positive or negative infinity
+    fn is_infinite(self) -> bool;
+    /// Returns `true` if the number is neither infinite nor `NaN`
+    fn is_finite(self) -> bool;
+    /// Returns `true` if the number is neither zero, infinite, subnormal nor `NaN`
+    fn is_normal(self) -> bool;
+}
+
+impl<T: Float> Real for T{
+    #[inline]
+    fn sqrt(self) -> Self{
+        Float::sqrt(self)
+    }
+    #[inline]
+    fn sin_cos(self) -> (Self, Self){
+        Float::sin_cos(self)
+    }
+    #[inline]
+    fn atan2(self, other: Self) -> Self{
+        Float::atan2(self, other)
+    }
+    #[inline]
+    fn is_nan(self) -> bool{
+        Float::is_nan(self)
+    }
+    #[inline]
+    fn is_infinite(self) -> bool{
+        Float::is_infinite(self)
+    }
+    #[inline]
+    fn is_finite(self) -> bool{
+        Float::is_finite(self)
+    }
+    #[inline]
+    fn is_normal(self) -> bool{
+        Float::is_normal(self)
+    }
+}
@@ -0,0 +1,117 @@
+// Operators taking their operands by reference, so scalars that are
+// expensive to copy, e.g. big rationals, don't need to be cloned
+
+use super::Vector2;
+
+use core::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg};
+
+macro_rules! impl_ref_binop {
+    ($($Op:ident, $op:ident;)*) => {$(
+        impl<'a, T: $Op<&'a T>> $Op<&'a Vector2<T>> for Vector2<T>{
+            type Output = Vector2<T::Output>;
+
+            fn $op(self, rhs: &'a Vector2<T>) -> Self::Output{
+                Vector2(self.0.$op(&rhs.0), self.1.$op(&rhs.1))
+            }
+        }
+
+        impl<'a, T> $Op<Vector2<T>> for &'a Vector2<T> where &'a T: $Op<T>{
+            type Output = Vector2<<&'a T as $Op<T>>::Output>;
+
+            fn $op(self, rhs: Vector2<T>) -> Self::Output{
+                Vector2((&self.0).$op(rhs.0), (&self.1).$op(rhs.1))
+            }
+        }
+
+        impl<'a, 'b, T> $Op<&'b Vector2<T>> for &'a Vector2<T> where &'a T: $Op<&'b T>{
+            type Output = Vector2<<&'a T as $Op<&'b T>>::Output>;
+
+            fn $op(self, rhs: &'b Vector2<T>) -> Self::Output{
+                Vector2((&self.0).$op(&rhs.0), (&self.1).$op(&rhs.1))
+            }
+        }
+    )*};
+}
+impl_ref_binop!{
+    Add, add;
+    Sub, sub;
+    Mul, mul;
+    Div, div;
+}
+
+macro_rules! impl_ref_scalar_op {
+    ($($Op:ident, $op:ident;)*) => {$(
+        impl<'a, T: $Op<&'a T>> $Op<&'a T> for Vector2<T>{
+            type Output = Vector2<T::Output>;
+
+            fn $op(self, rhs: &'a T) -> Self::Output{
+                Vector2(self.0.$op(rhs), self.1.$op(rhs))
+            }
+        }
+
+        impl<'a, 'b, T> $Op<&'b T> for &'a Vector2<T> where &'a T: $Op<&'b T>{
+            type Output = Vector2<<&'a T as $Op<&'b T>>::Output>;
+
+            fn $op(self, rhs: &'b T) -> Self::Output{
+                Vector2((&self.0).$op(rhs), (&self.1).$op(rhs))
+            }
+        }
+    )*};
+}
+impl_ref_scalar_op!{
+    Mul, mul;
+    Div, div;
+}
+
+macro_rules! impl_ref_assign_op {
+    ($($Op:ident, $op:ident;)*) => {$(
+        impl<'a, T: $Op<&'a T>> $Op<&'a Vector2<T>> for Vector2<T>{
+            fn $op(&mut self, rhs: &'a Vector2<T>){
+                self.0.$op(&rhs.0);
+                self.1.$op(&rhs.1);
+            }
+        }
+    )*};
+}
+impl_ref_assign_op!{
+    AddAssign, add_assign;
+    SubAssign, sub_assign;
+    MulAssign, mul_assign;
+    DivAssign, div_assign;
+}
+
+macro_rules! impl_ref_scalar_assign_op {
+    ($($Op:ident, $op:ident;)*) => {$(
+        impl<'a, T: $Op<&'a T>> $Op<&'a T> for Vector2<T>{
+            fn $op(&mut self, rhs: &'a T){
+                self.0.$op(rhs);
+                self.1.$op(rhs);
+            }
+        }
+    )*};
+}
+impl_ref_scalar_assign_op!{
+    MulAssign, mul_assign;
+    DivAssign, div_assign;
+}
+
+impl<'a, T> Neg for &'a Vector2<T> where &'a T: Neg{
+    type Output = Vector2<<&'a T as Neg>::Output>;
+
+    fn neg(self) -> Self::Output{
+        Vector2(-&self.0, -&self.1)
+    }
+}
+
+impl<T> Vector2<T>{
+    /// Returns the dot product of two borrowed vectors
+    pub fn dot_ref<'a>(&'a self, other: &'a Self) -> <<&'a T as Mul>::Output as Add>::Output
+    where &'a T: Mul, <&'a T as Mul>::Output: Add{
+        &self.0 * &other.0 + &self.1 * &other.1
+    }
+    /// Returns the determinant of two borrowed vectors
+    pub fn det_ref<'a>(&'a self, other: &'a Self) -> <<&'a T as Mul>::Output as Sub>::Output
+    where &'a T: Mul, <&'a T as Mul>::Output: Sub{
+        &self.0 * &other.1 - &self.1 * &other.0
+    }
+}
@@ -0,0 +1,112 @@
+use super::{Vector2, Real};
+
+use num_traits::{Zero, One};
+
+use core::ops::{Mul, MulAssign};
+
+/// A rotation in the plane
+///
+/// Stored as the cosine and sine of the angle i.e. a unit complex number,
+/// so applying it to a vector needs no trigonometry.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature="rustc-serialize", derive(RustcDecodable, RustcEncodable))]
+#[cfg_attr(feature="serde_derive", derive(Serialize, Deserialize))]
+pub struct Rotation2<T>{
+    cos: T,
+    sin: T,
+}
+
+impl<T: Real> Rotation2<T>{
+    /// Creates a rotation by an angle in radians
+    pub fn new(angle: T) -> Self{
+        let (sin, cos) = angle.sin_cos();
+        Rotation2{cos, sin}
+    }
+    /// Creates the rotation that turns the x-axis towards a vector
+    ///
+    /// Returns `None` if the vector is zero or not finite and thus has no direction.
+    pub fn from_vector(direction: Vector2<T>) -> Option<Self>{
+        let Vector2(cos, sin) = direction.try_normalise().ok()?;
+        Some(Rotation2{cos, sin})
+    }
+    /// Returns the cosine of the angle
+    pub fn cos(self) -> T{
+        self.cos
+    }
+    /// Returns the sine of the angle
+    pub fn sin(self) -> T{
+        self.sin
+    }
+    /// Returns the angle of the rotation in radians
+    pub fn angle(self) -> T{
+        self.sin.atan2(self.cos)
+    }
+    /// Returns the unit vector the x-axis is rotated onto
+    pub fn to_vector(self) -> Vector2<T>{
+        Vector2(self.cos, self.sin)
+    }
+    /// Returns the rotation in the opposite direction
+    pub fn inverse(self) -> Self{
+        Rotation2{cos: self.cos, sin: -self.sin}
+    }
+    /// Interpolates between two rotations at a constant angular speed taking the shortest way
+    ///
+    /// `t = 0` gives `self` and `t = 1` gives `other`.
+    pub fn slerp(self, other: Self, t: T) -> Self{
+        let delta = (self.inverse() * other).angle();
+        self * Rotation2::new(delta * t)
+    }
+}
+
+impl<T: Zero + One> Rotation2<T>{
+    /// The rotation that does nothing
+    pub fn identity() -> Self{
+        Rotation2{cos: T::one(), sin: T::zero()}
+    }
+}
+
+impl<T: Real> Mul for Rotation2<T>{
+    type Output = Rotation2<T>;
+
+    /// Composes two rotations, so the angles are added
+    fn mul(self, rhs: Self) -> Self{
+        Rotation2{
+            cos: self.cos * rhs.cos - self.sin * rhs.sin,
+            sin: self.cos * rhs.sin + self.sin * rhs.cos,
+        }
+    }
+}
+
+impl<T: Real> MulAssign for Rotation2<T>{
+    fn mul_assign(&mut self, rhs: Self){
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Real> Mul<Vector2<T>> for Rotation2<T>{
+    type Output = Vector2<T>;
+
+    fn mul(self, rhs: Vector2<T>) -> Vector2<T>{
+        rhs.rotate_by_unit(self.to_vector())
+    }
+}
+
+impl<T: Real> Vector2<T>{
+    /// Rotates the vector counter-clockwise by an angle in radians
+    pub fn rotate(self, angle: T) -> Self{
+        self.rotate_by_unit(Vector2::unit_vector(angle))
+    }
+    /// Rotates the vector counter-clockwise around a pivot by an angle in radians
+    pub fn rotate_around(self, pivot: Self, angle: T) -> Self{
+        (self - pivot).rotate(angle) + pivot
+    }
+    /// Rotates the vector by the direction of a unit vector
+    ///
+    /// This is complex multiplication, so rotating by `unit_vector(a)` is the same as `rotate(a)`.
+    pub fn rotate_by_unit(self, unit: Self) -> Self{
+        Vector2(
+            self.0 * unit.0 - self.1 * unit.1,
+            self.0 * unit.1 + self.1 * unit.0,
+        )
+    }
+}
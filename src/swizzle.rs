@@ -0,0 +1,47 @@
+use super::{Vector2, Vector3};
+
+use num_traits::{Zero, One};
+
+impl<T> Vector2<T>{
+    /// Returns the vector unchanged, for symmetry with the other swizzles
+    pub fn xy(self) -> Self{
+        self
+    }
+    /// Returns the vector with its components swapped
+    pub fn yx(self) -> Self{
+        Vector2(self.1, self.0)
+    }
+    /// Returns the vector with the x component replaced
+    pub fn with_x(self, x: T) -> Self{
+        Vector2(x, self.1)
+    }
+    /// Returns the vector with the y component replaced
+    pub fn with_y(self, y: T) -> Self{
+        Vector2(self.0, y)
+    }
+}
+
+impl<T: Copy> Vector2<T>{
+    /// Returns a vector with the x component in both places
+    pub fn xx(self) -> Self{
+        Vector2(self.0, self.0)
+    }
+    /// Returns a vector with the y component in both places
+    pub fn yy(self) -> Self{
+        Vector2(self.1, self.1)
+    }
+}
+
+impl<T: Zero> Vector2<T>{
+    /// Extends the vector with a zero, i.e. homogeneous coordinates for a direction
+    pub fn xy0(self) -> Vector3<T>{
+        self.extend(T::zero())
+    }
+}
+
+impl<T: One> Vector2<T>{
+    /// Extends the vector with a one, i.e. homogeneous coordinates for a point
+    pub fn xy1(self) -> Vector3<T>{
+        self.extend(T::one())
+    }
+}
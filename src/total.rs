@@ -0,0 +1,111 @@
+use super::Vector2;
+
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+/// Scalars that can be compared and hashed using a total order
+///
+/// For floats this is the IEEE 754 `totalOrder` predicate applied after canonicalising,
+/// so `-0.0` equals `0.0`, every NaN equals every other NaN and NaN is greater than infinity.
+pub trait TotalOrder: Copy{
+    /// The key compared and hashed in place of the scalar
+    type Key: Ord + Hash;
+
+    /// Returns the canonical representative of the scalar
+    fn canonicalise(self) -> Self;
+    /// Returns a key whose order is the total order of the canonical scalar
+    fn total_key(self) -> Self::Key;
+}
+
+macro_rules! impl_total_order {
+    ($($F:ident($bits:ident, $shift:expr)),*) => {$(
+        impl TotalOrder for $F{
+            type Key = $bits;
+
+            fn canonicalise(self) -> Self{
+                if self.is_nan() {
+                    $F::NAN
+                } else if self == 0. {
+                    0.
+                } else {
+                    self
+                }
+            }
+            fn total_key(self) -> $bits{
+                // Flip the magnitude bits of negative numbers so they compare as integers
+                let bits = self.canonicalise().to_bits() as $bits;
+                bits ^ ((bits >> $shift) & $bits::MAX)
+            }
+        }
+    )*};
+}
+
+impl_total_order!(f32(i32, 31), f64(i64, 63));
+
+/// Wrapper around a `Vector2` that implements `Eq`, `Ord` and `Hash` for float components
+///
+/// Components are canonicalised on construction and compared lexicographically with a total order,
+/// which makes it usable as a `HashMap`/`BTreeMap` key e.g. to deduplicate vertices.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct TotalVector2<T>(Vector2<T>);
+
+impl<T: TotalOrder> TotalVector2<T>{
+    /// Wraps a vector, canonicalising its components
+    pub fn new(vector: Vector2<T>) -> Self{
+        TotalVector2(Vector2(vector.0.canonicalise(), vector.1.canonicalise()))
+    }
+}
+
+impl<T: Copy> TotalVector2<T>{
+    /// Returns the canonicalised vector
+    pub fn get(self) -> Vector2<T>{
+        self.0
+    }
+}
+
+impl<T: TotalOrder> Vector2<T>{
+    /// Wraps the vector in a `TotalVector2` to hash or totally order it
+    pub fn total(self) -> TotalVector2<T>{
+        TotalVector2::new(self)
+    }
+}
+
+impl<T: TotalOrder> From<Vector2<T>> for TotalVector2<T>{
+    fn from(vector: Vector2<T>) -> Self{
+        TotalVector2::new(vector)
+    }
+}
+
+impl<T> From<TotalVector2<T>> for Vector2<T>{
+    fn from(total: TotalVector2<T>) -> Self{
+        total.0
+    }
+}
+
+impl<T: TotalOrder> PartialEq for TotalVector2<T>{
+    fn eq(&self, other: &Self) -> bool{
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: TotalOrder> Eq for TotalVector2<T>{}
+
+impl<T: TotalOrder> PartialOrd for TotalVector2<T>{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering>{
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: TotalOrder> Ord for TotalVector2<T>{
+    fn cmp(&self, other: &Self) -> Ordering{
+        (self.0).0.total_key().cmp(&(other.0).0.total_key())
+            .then_with(|| (self.0).1.total_key().cmp(&(other.0).1.total_key()))
+    }
+}
+
+impl<T: TotalOrder> Hash for TotalVector2<T>{
+    fn hash<H: Hasher>(&self, state: &mut H){
+        (self.0).0.total_key().hash(state);
+        (self.0).1.total_key().hash(state);
+    }
+}
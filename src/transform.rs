@@ -0,0 +1,118 @@
+use super::{Vector2, Rotation2, Matrix3};
+
+use num_traits::Float;
+
+use core::ops::{Mul, MulAssign};
+
+/// A translation, rotation and uniform scale, applied in the reverse order
+///
+/// The scale is uniform so that transforms stay closed under composition and inversion;
+/// rotating a non-uniformly scaled shape would introduce shear.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature="rustc-serialize", derive(RustcDecodable, RustcEncodable))]
+#[cfg_attr(feature="serde_derive", derive(Serialize, Deserialize))]
+pub struct Transform2<T>{
+    /// How far the origin is moved
+    pub translation: Vector2<T>,
+    /// The rotation around the origin
+    pub rotation: Rotation2<T>,
+    /// The factor to scale by
+    pub scale: T,
+}
+
+impl<T: Float> Transform2<T>{
+    /// Creates a new transform from a translation, an angle in radians and a scale
+    pub fn new(translation: Vector2<T>, angle: T, scale: T) -> Self{
+        Transform2{translation, rotation: Rotation2::new(angle), scale}
+    }
+    /// The transform that does nothing
+    pub fn identity() -> Self{
+        Transform2{
+            translation: Vector2(T::zero(), T::zero()),
+            rotation: Rotation2::identity(),
+            scale: T::one(),
+        }
+    }
+    /// Transforms a vector as a point, so it is affected by translation
+    pub fn transform_point(self, point: Vector2<T>) -> Vector2<T>{
+        self.transform_vector(point) + self.translation
+    }
+    /// Transforms a vector as a direction, so it is not affected by translation
+    pub fn transform_vector(self, vector: Vector2<T>) -> Vector2<T>{
+        self.rotation * (vector * self.scale)
+    }
+    /// Returns the transform that undoes this one
+    ///
+    /// A transform with a scale of zero has no inverse and gives a non-finite result.
+    pub fn inverse(self) -> Self{
+        let rotation = self.rotation.inverse();
+        let scale = self.scale.recip();
+        Transform2{
+            translation: -(rotation * self.translation) * scale,
+            rotation,
+            scale,
+        }
+    }
+    /// Interpolates between two transforms, rotating the shortest way
+    ///
+    /// `t = 0` gives `self` and `t = 1` gives `other`.
+    pub fn lerp(self, other: Self, t: T) -> Self{
+        let t = t.max(T::zero()).min(T::one());
+        Transform2{
+            translation: self.translation.lerp(other.translation, t),
+            rotation: self.rotation.slerp(other.rotation, t),
+            scale: self.scale + (other.scale - self.scale) * t,
+        }
+    }
+    /// Returns the homogeneous matrix performing this transform
+    pub fn to_matrix(self) -> Matrix3<T>{
+        let x = self.rotation.to_vector() * self.scale;
+        Matrix3(x.extend(T::zero()), x.normal().extend(T::zero()), self.translation.extend(T::one()))
+    }
+    /// Extracts the transform from a homogeneous matrix
+    ///
+    /// Returns `None` if the matrix is not affine or if it flips or collapses space.
+    /// Shear and non-uniform scale cannot be represented and are discarded.
+    pub fn from_matrix(matrix: Matrix3<T>) -> Option<Self>{
+        let Matrix3(x, y, t) = matrix;
+        let zero = T::zero();
+        if x.2 != zero || y.2 != zero || t.2 != T::one() {
+            return None;
+        }
+        let (x, y) = (x.truncate(), y.truncate());
+        if x.det(y) <= zero {
+            return None;
+        }
+        Some(Transform2{
+            translation: t.truncate(),
+            rotation: Rotation2::from_vector(x)?,
+            scale: x.length(),
+        })
+    }
+}
+
+impl<T: Float> Mul for Transform2<T>{
+    type Output = Transform2<T>;
+
+    /// Composes two transforms, so `rhs` is applied first
+    fn mul(self, rhs: Self) -> Self{
+        Transform2{
+            translation: self.transform_point(rhs.translation),
+            rotation: self.rotation * rhs.rotation,
+            scale: self.scale * rhs.scale,
+        }
+    }
+}
+
+impl<T: Float> MulAssign for Transform2<T>{
+    fn mul_assign(&mut self, rhs: Self){
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Float> From<Transform2<T>> for Matrix3<T>{
+    #[inline]
+    fn from(transform: Transform2<T>) -> Self{
+        transform.to_matrix()
+    }
+}
@@ -0,0 +1,63 @@
+use super::{Vector3, Real};
+
+use core::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg};
+
+/// Representation of a four-dimensional vector e.g. homogeneous coordinates
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature="rustc-serialize", derive(RustcDecodable, RustcEncodable))]
+#[cfg_attr(feature="serde_derive", derive(Serialize, Deserialize))]
+pub struct Vector4<T>(pub T, pub T, pub T, pub T);
+
+impl<T: Real> Vector4<T>{
+    /// Normalises the vector
+    pub fn normalise(self) -> Self{
+        self / self.length()
+    }
+    /// Returns the magnitude/length of the vector
+    pub fn length(self) -> T{
+        T::magnitude_n(&[self.0, self.1, self.2, self.3])
+    }
+    /// Returns the magnitude/length of the vector squared
+    pub fn length_squared(self) -> T{
+        self.0 * self.0 + self.1 * self.1 + self.2 * self.2 + self.3 * self.3
+    }
+    /// Returns the distance betweens two vectors
+    pub fn distance_to(self, other: Self) -> T{
+        (other-self).length()
+    }
+    /// Returns the distance betweens two vectors squared
+    pub fn distance_to_squared(self, other: Self) -> T{
+        (other-self).length_squared()
+    }
+    /// Returns `true` if any component is `NaN`.
+    pub fn is_any_nan(&self) -> bool{
+        self.0.is_nan() || self.1.is_nan() || self.2.is_nan() || self.3.is_nan()
+    }
+    /// Returns `true` if any component is positive or negative infinity.
+    pub fn is_any_infinite(&self) -> bool{
+        self.0.is_infinite() || self.1.is_infinite() || self.2.is_infinite() || self.3.is_infinite()
+    }
+    /// Returns `true` if all components are neither infinite nor `NaN`.
+    pub fn is_all_finite(&self) -> bool{
+        self.0.is_finite() && self.1.is_finite() && self.2.is_finite() && self.3.is_finite()
+    }
+    /// Returns `true` if all components are neither zero, infinite, subnormal nor `NaN`.
+    pub fn is_all_normal(&self) -> bool{
+        self.0.is_normal() && self.1.is_normal() && self.2.is_normal() && self.3.is_normal()
+    }
+}
+
+impl<T> Vector4<T>{
+    /// Returns the dot product of two vectors
+    pub fn dot(self, other: Self) -> T
+    where T: Mul<Output=T> + Add<Output=T>{
+        self.0 * other.0 + self.1 * other.1 + self.2 * other.2 + self.3 * other.3
+    }
+    /// Returns the vector without its last component
+    pub fn truncate(self) -> Vector3<T>{
+        Vector3(self.0, self.1, self.2)
+    }
+}
+
+impl_vector_ops!(Vector4, 4, (T, T, T, T), 0 1 2 3);
+impl_scalar_lhs!(Vector4 [0 1 2 3]; f32 f64 i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
@@ -0,0 +1,54 @@
+extern crate simple_vector2d;
+
+use simple_vector2d::Vector2;
+use simple_vector2d::aggregate::{centroid, weighted_centroid, bounding_box, min_by_length, nearest, farthest};
+
+#[test]
+fn summing(){
+    let points = vec![Vector2(1., 2.), Vector2(3., 4.), Vector2(-1., 0.)];
+
+    assert_eq!(points.iter().sum::<Vector2<f64>>(), Vector2(3., 6.));
+    assert_eq!(points.into_iter().sum::<Vector2<f64>>(), Vector2(3., 6.));
+    assert_eq!(vec![Vector2(1, 2); 3].into_iter().sum::<Vector2<i32>>(), Vector2(3, 6));
+    assert_eq!(Vec::<Vector2<i32>>::new().iter().sum::<Vector2<i32>>(), Vector2(0, 0));
+}
+
+#[test]
+fn multiplying(){
+    let scales = vec![Vector2(2., 3.), Vector2(0.5, -1.), Vector2(4., 2.)];
+
+    assert_eq!(scales.iter().product::<Vector2<f64>>(), Vector2(4., -6.));
+    assert_eq!(scales.into_iter().product::<Vector2<f64>>(), Vector2(4., -6.));
+    assert_eq!(vec![Vector2(2, 3); 3].into_iter().product::<Vector2<i32>>(), Vector2(8, 27));
+    assert_eq!(Vec::<Vector2<u8>>::new().iter().product::<Vector2<u8>>(), Vector2(1, 1));
+}
+
+#[test]
+fn summaries(){
+    let points = vec![Vector2(0., 0.), Vector2(4., 0.), Vector2(4., 2.), Vector2(0., 2.)];
+    let none: Vec<Vector2<f64>> = Vec::new();
+
+    assert_eq!(centroid(&points), Some(Vector2(2., 1.)));
+    assert_eq!(centroid(&none), None);
+    assert_eq!(weighted_centroid(vec![(Vector2(0., 0.), 3.), (Vector2(4., 4.), 1.)]), Some(Vector2(1., 1.)));
+    assert_eq!(weighted_centroid(vec![(Vector2(0., 0.), 0.)]), None);
+    assert_eq!(bounding_box(&points), Some((Vector2(0., 0.), Vector2(4., 2.))));
+    assert_eq!(bounding_box(vec![Vector2(-1, 5), Vector2(3, -2)]), Some((Vector2(-1, -2), Vector2(3, 5))));
+    assert_eq!(bounding_box(&none), None);
+    assert_eq!(min_by_length(&points[1..]), Some(Vector2(0., 2.)));
+    assert_eq!(min_by_length(&none), None);
+    assert_eq!(nearest(&points, Vector2(3., 3.)), Some(Vector2(4., 2.)));
+    assert_eq!(farthest(&points, Vector2(3., 3.)), Some(Vector2(0., 0.)));
+    assert_eq!(nearest(points.iter().map(|p| *p * 2.), Vector2(3., 3.)), Some(Vector2(0., 4.)));
+}
+
+#[test]
+fn skipping_nan(){
+    let nan = Vector2(f64::NAN, 0.);
+    let points = vec![nan, Vector2(1., 0.), Vector2(3., 0.)];
+
+    assert_eq!(min_by_length(&points), Some(Vector2(1., 0.)));
+    assert_eq!(nearest(&points, Vector2(0., 0.)), Some(Vector2(1., 0.)));
+    assert_eq!(farthest(&points, Vector2(0., 0.)), Some(Vector2(3., 0.)));
+    assert!(min_by_length(vec![nan, nan]).unwrap().is_any_nan());
+}
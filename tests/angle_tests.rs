@@ -0,0 +1,38 @@
+extern crate simple_vector2d;
+
+use simple_vector2d::{Vector2, Rad, Deg};
+
+use std::f64::consts::{PI, FRAC_PI_2, FRAC_PI_4};
+
+#[test]
+fn conversions(){
+    assert_eq!(Rad::from(Deg(180.)), Rad(PI));
+    assert_eq!(Deg::from(Rad(FRAC_PI_2)), Deg(90.));
+    assert_eq!(Deg(30.) + Deg(60.), Deg(90.));
+    assert_eq!(Rad(1.) - Rad(0.5), Rad(0.5));
+    assert_eq!(-Deg(45.) * 2., Deg(-90.));
+}
+
+#[test]
+fn normalisation(){
+    assert_eq!(Deg(370.).wrap_positive(), Deg(10.));
+    assert_eq!(Deg(-10.).wrap_positive(), Deg(350.));
+    assert_eq!(Deg(-360.).wrap_positive(), Deg(0.));
+    assert_eq!(Deg(190.).wrap_signed(), Deg(-170.));
+    assert_eq!(Deg(-180.).wrap_signed(), Deg(180.));
+    assert_eq!(Rad(-FRAC_PI_2).wrap_positive(), Rad(3. * FRAC_PI_2));
+    assert_eq!(Rad(3. * PI).wrap_signed(), Rad(PI));
+    assert!(Rad(-1e-20f64).wrap_positive().0 < 2. * PI);
+
+    assert_eq!(Deg(350.).shortest_difference(Deg(10.)), Deg(20.));
+    assert_eq!(Deg(10.).shortest_difference(Deg(350.)), Deg(-20.));
+}
+
+#[test]
+fn typed_directions(){
+    let v = Vector2::from_angle(Deg(90f64));
+    assert!((v - Vector2(0., 1.)).length() < 1e-15);
+    assert_eq!(Vector2::from_angle(Rad(0f64)), Vector2(1., 0.));
+    assert_eq!(Vector2(1., 1.).angle(), Rad(FRAC_PI_4));
+    assert_eq!(Vector2(2., 2.).angle_to(Vector2(4., 4.)), Rad(FRAC_PI_4));
+}
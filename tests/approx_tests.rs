@@ -0,0 +1,48 @@
+#[macro_use]
+extern crate simple_vector2d;
+
+use simple_vector2d::{Vector2, ApproxEq};
+
+#[test]
+fn tolerances(){
+    let v = Vector2(0.1 + 0.2, 3.);
+    let w = Vector2(0.3, 3.);
+
+    assert!(v != w);
+    assert!(v.approx_eq(&w));
+    assert!(v.abs_diff_eq(&w, 1e-15));
+    assert!(!v.abs_diff_eq(&w, 1e-17));
+    assert!(v.relative_eq(&w, 0., 1e-15));
+    assert!(v.ulps_eq(&w, 0., 1));
+    assert!(!Vector2(1e10, 0.).relative_eq(&Vector2(1.1e10, 0.), 1., 1e-3));
+    assert!(!Vector2(f32::INFINITY, 0.).approx_eq(&Vector2(f32::MAX, 0.)));
+    assert!(!Vector2(f64::NAN, 0.).approx_eq(&Vector2(f64::NAN, 0.)));
+    assert!(!(-1e-40f32).ulps_eq(&1e-40, 0., 4));
+    assert!(!f64::NAN.ulps_eq(&f64::NAN, 0., 4));
+    assert!(!Vector2(f64::NAN, 0.).ulps_eq(&Vector2(f64::NAN, 0.), 0., 4));
+    assert!(!f64::INFINITY.ulps_eq(&f64::MAX, 0., 4));
+    assert!(!f32::NEG_INFINITY.ulps_eq(&f32::MIN, 0., 4));
+    assert!(f64::INFINITY.ulps_eq(&f64::INFINITY, 0., 4));
+}
+
+#[test]
+fn assertion_macros(){
+    let v = Vector2(3., 4.);
+
+    assert_vec_approx_eq!(v.normalise(), Vector2::unit_vector(0.9272952180016122));
+    assert_vec_approx_eq!(v.normalise(), Vector2(0.6, 0.8), epsilon = 1e-15);
+    assert_vec_approx_eq!(v.rotate(std::f64::consts::PI), -v, max_relative = 1e-15);
+    assert_vec_approx_eq!(Vector2(0.1f32 + 0.2, 1.), Vector2(0.3, 1.), max_ulps = 1);
+}
+
+#[test]
+#[should_panic]
+fn failing_assertion(){
+    assert_vec_approx_eq!(Vector2(1., 2.), Vector2(1., 2.1), epsilon = 0.01);
+}
+
+#[test]
+fn qualified_assertion_macro(){
+    simple_vector2d::assert_vec_approx_eq!(Vector2(0.1 + 0.2, 1.), Vector2(0.3, 1.));
+    simple_vector2d::assert_vec_approx_eq!(Vector2(0.1 + 0.2, 1.), Vector2(0.3, 1.), max_ulps = 1);
+}
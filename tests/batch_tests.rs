@@ -0,0 +1,75 @@
+#[macro_use]
+extern crate simple_vector2d;
+
+use simple_vector2d::Vector2;
+use simple_vector2d::batch::{Vector2x4, Vector2x8};
+
+#[test]
+fn packing(){
+    let vectors = [Vector2(1., 2.), Vector2(3., 4.), Vector2(-5., 6.), Vector2(7., -8.)];
+    let mut batch = Vector2x4::from(vectors);
+    assert_eq!(batch, Vector2x4([1., 3., -5., 7.], [2., 4., 6., -8.]));
+    assert_eq!(<[Vector2<f32>; 4]>::from(batch), vectors);
+    assert_eq!(batch.extract(2), Vector2(-5., 6.));
+
+    batch.replace(2, Vector2(0., 0.));
+    assert_eq!(batch.extract(2), Vector2(0., 0.));
+    assert_eq!(Vector2x8::splat(Vector2(1., 2.)).extract(7), Vector2(1., 2.));
+    assert_eq!(Vector2x8::LANES, 8);
+}
+
+#[test]
+fn lane_operators(){
+    let vectors = [
+        Vector2(1., 2.), Vector2(3., 4.), Vector2(-5., 6.), Vector2(7., -8.),
+        Vector2(0.5, 0.), Vector2(0., -0.25), Vector2(9., 9.), Vector2(-1., -1.),
+    ];
+    let a = Vector2x8::from(vectors);
+    let b = Vector2x8::splat(Vector2(2., -1.));
+
+    let sum: [Vector2<f32>; 8] = (a + b).into();
+    let difference: [Vector2<f32>; 8] = (a - b).into();
+    let scaled: [Vector2<f32>; 8] = (-(2. * a) / 4.).into();
+    for (i, &v) in vectors.iter().enumerate() {
+        assert_eq!(sum[i], v + Vector2(2., -1.));
+        assert_eq!(difference[i], v - Vector2(2., -1.));
+        assert_eq!(scaled[i], -v * 0.5);
+    }
+
+    let mut c = a;
+    c += b;
+    c -= b;
+    c *= 3.;
+    c /= 3.;
+    assert_eq!(c, a);
+    assert_eq!((a * [2.; 8]) / [2.; 8], a);
+
+    let scale = Vector2x8::splat(Vector2(2., 0.5));
+    let scaled: [Vector2<f32>; 8] = (a * scale).into();
+    let shrunk: [Vector2<f32>; 8] = (a / scale).into();
+    for (i, &v) in vectors.iter().enumerate() {
+        assert_eq!(scaled[i], v * Vector2(2., 0.5));
+        assert_eq!(shrunk[i], v / Vector2(2., 0.5));
+    }
+    c *= scale;
+    c /= scale;
+    assert_eq!(c, a);
+}
+
+#[test]
+fn lane_geometry(){
+    let vectors = [Vector2(3., 4.), Vector2(-1., 1.), Vector2(0., -2.), Vector2(5., 12.)];
+    let a = Vector2x4::from(vectors);
+    let b = Vector2x4::splat(Vector2(1., 2.));
+
+    let (length, length_squared) = (a.length(), a.length_squared());
+    let (dot, det) = (a.dot(b), a.det(b));
+    let normalised: [Vector2<f32>; 4] = a.normalise().into();
+    for (i, &v) in vectors.iter().enumerate() {
+        assert_eq!(length[i], v.length());
+        assert_eq!(length_squared[i], v.length_squared());
+        assert_eq!(dot[i], v.dot(Vector2(1., 2.)));
+        assert_eq!(det[i], v.det(Vector2(1., 2.)));
+        assert_vec_approx_eq!(normalised[i], v.normalise());
+    }
+}
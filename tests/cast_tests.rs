@@ -0,0 +1,34 @@
+extern crate simple_vector2d;
+
+use simple_vector2d::Vector2;
+
+#[test]
+fn checked_casts(){
+    assert_eq!(Vector2(1.5f64, -2.).cast::<f32>(), Some(Vector2(1.5f32, -2.)));
+    assert_eq!(Vector2(1.5f64, -2.7).cast::<i32>(), Some(Vector2(1, -2)));
+    assert_eq!(Vector2(-1i32, 2).cast::<u8>(), None);
+    assert_eq!(Vector2(f64::NAN, 0.).cast::<i32>(), None);
+    assert_eq!(Vector2(300u32, 2).cast::<i16>(), Some(Vector2(300i16, 2)));
+}
+
+#[test]
+fn lossy_casts(){
+    let physics = Vector2(1.25f64, -3.75);
+
+    assert_eq!(physics.as_f32(), Vector2(1.25f32, -3.75));
+    assert_eq!(Vector2(3i32, 4).as_f64(), Vector2(3., 4.));
+    assert_eq!(physics.as_i32(), Vector2(1, -3));
+    assert_eq!(physics.round_to_i32(), Vector2(1, -4));
+    assert_eq!(physics.floor_to_i32(), Vector2(1, -4));
+    assert_eq!(Vector2(-0.5f32, 0.5).floor_to_i32(), Vector2(-1, 0));
+    assert_eq!(Vector2(1e20f64, f64::NAN).as_i32(), Vector2(i32::MAX, 0));
+}
+
+#[test]
+fn widening(){
+    assert_eq!(Vector2::<f64>::from(Vector2(1.5f32, 2.)), Vector2(1.5f64, 2.));
+    assert_eq!(Vector2::<i32>::from(Vector2(-3i16, 4)), Vector2(-3i32, 4));
+    assert_eq!(Vector2::<f64>::from(Vector2(u32::MAX, 0)), Vector2(u32::MAX as f64, 0.));
+    let v: Vector2<i64> = Vector2(255u8, 0).into();
+    assert_eq!(v, Vector2(255, 0));
+}
@@ -0,0 +1,46 @@
+extern crate simple_vector2d;
+
+use simple_vector2d::Vector2;
+
+#[test]
+fn componentwise_operators(){
+    let sprite = Vector2(10., 20.);
+    let scale = Vector2(2., 0.5);
+
+    assert_eq!(sprite * scale, Vector2(20., 10.));
+    assert_eq!(sprite / scale, Vector2(5., 40.));
+    assert_eq!(sprite * 2., Vector2(20., 40.));
+    assert_eq!(Vector2(6, 8) / Vector2(3, 2), Vector2(2, 4));
+
+    let mut v = sprite;
+    v *= scale;
+    assert_eq!(v, Vector2(20., 10.));
+    v /= scale;
+    assert_eq!(v, sprite);
+}
+
+#[test]
+fn elementwise_helpers(){
+    let v = Vector2(-1.5, 2.5);
+
+    assert_eq!(v.abs(), Vector2(1.5, 2.5));
+    assert_eq!(Vector2(-3, 0).abs(), Vector2(3, 0));
+    assert_eq!(v.signum(), Vector2(-1., 1.));
+    assert_eq!(v.floor(), Vector2(-2., 2.));
+    assert_eq!(v.ceil(), Vector2(-1., 3.));
+    assert_eq!(v.round(), Vector2(-2., 3.));
+    assert_eq!(Vector2(2., 4.).recip(), Vector2(0.5, 0.25));
+    assert_eq!(v.mul_add(Vector2(2., 2.), Vector2(1., 1.)), Vector2(-2., 6.));
+    assert_eq!(v.min(Vector2(0., 0.)), Vector2(-1.5, 0.));
+    assert_eq!(v.max(Vector2(0., 0.)), Vector2(0., 2.5));
+    assert_eq!(Vector2(-5, 5).clamp(Vector2(-1, -1), Vector2(1, 1)), Vector2(-1, 1));
+    assert_eq!(v.min_element(), -1.5);
+    assert_eq!(v.max_element(), 2.5);
+}
+
+#[test]
+fn combinators(){
+    assert_eq!(Vector2(1, 2).map(|c| c * 10), Vector2(10, 20));
+    assert_eq!(Vector2(1, 2).map(|c| c as f32 / 2.), Vector2(0.5, 1.));
+    assert_eq!(Vector2(1, 2).zip_with(Vector2(3, 4), |a, b| a * b), Vector2(3, 8));
+}
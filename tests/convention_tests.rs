@@ -0,0 +1,42 @@
+#[macro_use]
+extern crate simple_vector2d;
+
+use simple_vector2d::Vector2;
+use simple_vector2d::convention::{Convention, YUp, YDown};
+
+use std::f64::consts::FRAC_PI_2;
+
+#[test]
+fn directions(){
+    assert_eq!(YUp::up::<f64>(), Vector2(0., 1.));
+    assert_eq!(YUp::down::<f64>(), Vector2(0., -1.));
+    assert_eq!(YDown::up::<i32>(), Vector2(0, -1));
+    assert_eq!(YDown::down::<i32>(), Vector2(0, 1));
+    assert_eq!(YDown::left::<i32>(), Vector2(-1, 0));
+    assert_eq!(YDown::right::<i32>(), YUp::right());
+    assert_eq!(YUp::rotation_sign::<f32>(), 1.);
+    assert_eq!(YDown::rotation_sign::<f32>(), -1.);
+}
+
+#[test]
+fn turning(){
+    let right = Vector2(1., 0.);
+
+    assert_eq!(YUp::normal(right), right.normal());
+    assert_eq!(YUp::normal(right), YUp::up());
+    assert_eq!(YDown::normal(right), YDown::up());
+    assert_vec_approx_eq!(YUp::rotate(right, FRAC_PI_2), YUp::up());
+    assert_vec_approx_eq!(YDown::rotate(right, FRAC_PI_2), YDown::up());
+}
+
+#[test]
+fn converting(){
+    let world = Vector2(3, 4);
+
+    assert_eq!(YUp::convert::<YUp, _>(world), world);
+    assert_eq!(YUp::convert::<YDown, _>(world), Vector2(3, -4));
+    assert_eq!(YDown::convert::<YUp, _>(YUp::convert::<YDown, _>(world)), world);
+    assert_eq!(YUp::convert::<YDown, _>(YUp::up::<i32>()), YDown::up());
+    assert_eq!(YUp::convert_point::<YDown, _>(world, 10), Vector2(3, 6));
+    assert_eq!(YDown::convert_point::<YDown, _>(world, 10), world);
+}
@@ -0,0 +1,82 @@
+extern crate simple_vector2d;
+
+use simple_vector2d::{Vector2, Vector3, Vector4};
+
+#[test]
+fn three_dimensions(){
+    let v0 = Vector3(1., 2., 2.);
+    let v1 = Vector3(4., 0., -1.);
+
+    assert_eq!(v0+v1, Vector3(5., 2., 1.));
+    assert_eq!(v0-v1, Vector3(-3., 2., 3.));
+    assert_eq!(v0*2., Vector3(2., 4., 4.));
+    assert_eq!(2.*v0, Vector3(2., 4., 4.));
+    assert_eq!(v0/2., Vector3(0.5, 1., 1.));
+    assert_eq!(-v0, Vector3(-1., -2., -2.));
+    assert_eq!(v0.dot(v1), 2.);
+    assert_eq!(v0.length(), 3.);
+    assert_eq!(v0.length_squared(), 9.);
+    assert_eq!(v0.normalise().length(), 1.);
+    assert_eq!(Vector3(1., 0., 0.).cross(Vector3(0., 1., 0.)), Vector3(0., 0., 1.));
+    assert!((v0/0.).is_any_infinite());
+    assert!(v0.is_all_finite() && v0.is_all_normal());
+
+    let mut v = v0;
+    v += v1;
+    v -= v0;
+    v *= 2.;
+    v /= 4.;
+    assert_eq!(v, Vector3(2., 0., -0.5));
+}
+
+#[test]
+fn four_dimensions(){
+    let v0 = Vector4(1., 1., 1., 1.);
+    let v1 = Vector4(1., 2., 3., 4.);
+
+    assert_eq!(v0+v1, Vector4(2., 3., 4., 5.));
+    assert_eq!(v1-v0, Vector4(0., 1., 2., 3.));
+    assert_eq!(v0.dot(v1), 10.);
+    assert_eq!(v0.length(), 2.);
+    assert_eq!(v0.distance_to(v0*3.), 4.);
+    assert!(!v1.is_any_nan());
+}
+
+#[test]
+fn changing_dimensions(){
+    let v = Vector2(1, 2);
+
+    assert_eq!(v.extend(3), Vector3(1, 2, 3));
+    assert_eq!(v.extend(3).extend(4), Vector4(1, 2, 3, 4));
+    assert_eq!(Vector4(1, 2, 3, 4).truncate(), Vector3(1, 2, 3));
+    assert_eq!(Vector3(1, 2, 3).truncate(), v);
+    assert_eq!(Vector3::from((1, 2, 3)), Vector3::from([1, 2, 3]));
+    assert_eq!(Into::<[i32; 4]>::into(Vector4(1, 2, 3, 4)), [1, 2, 3, 4]);
+    assert_eq!(Into::<(i32, i32, i32)>::into(Vector3(1, 2, 3)), (1, 2, 3));
+}
+
+#[test]
+fn swizzling(){
+    let v = Vector2(1, 2);
+
+    assert_eq!(v.xy(), v);
+    assert_eq!(v.yx(), Vector2(2, 1));
+    assert_eq!(v.xx(), Vector2(1, 1));
+    assert_eq!(v.yy(), Vector2(2, 2));
+    assert_eq!(v.with_x(5), Vector2(5, 2));
+    assert_eq!(v.with_y(5), Vector2(1, 5));
+    assert_eq!(v.xy0(), Vector3(1, 2, 0));
+    assert_eq!(v.xy1(), Vector3(1, 2, 1));
+}
+
+#[test]
+fn large_fixed_point_lengths(){
+    use simple_vector2d::fixed::Fixed16;
+
+    let (zero, big) = (Fixed16::ZERO, Fixed16::from_int(200));
+    // The squared lengths would overflow Q16.16
+    assert_eq!(Vector3(big, zero, zero).length(), big);
+    assert_eq!(Vector3(Fixed16::from_int(2000), Fixed16::from_int(3000), Fixed16::from_int(6000)).length(), Fixed16::from_int(7000));
+    assert_eq!(Vector4(zero, zero, zero, -big).length(), big);
+    assert_eq!(Vector4(big, big, big, big).length(), Fixed16::from_int(400));
+}
@@ -0,0 +1,99 @@
+extern crate simple_vector2d;
+
+use simple_vector2d::Vector2;
+use simple_vector2d::fixed::{Fixed16, Fixed32};
+
+use std::f64::consts::{FRAC_PI_4, PI};
+
+fn close(a: f64, b: f64, tolerance: f64) -> bool{
+    (a - b).abs() <= tolerance
+}
+
+#[test]
+fn arithmetic(){
+    let a = Fixed16::from_int(3);
+    let b = Fixed16::from_f64(0.5);
+
+    assert_eq!((a + b).to_f64(), 3.5);
+    assert_eq!((a - b).to_f64(), 2.5);
+    assert_eq!((a * b).to_f64(), 1.5);
+    assert_eq!((a / b).to_f64(), 6.);
+    assert_eq!((-a).to_f64(), -3.);
+    assert_eq!(Fixed16::from_int(16).sqrt(), Fixed16::from_int(4));
+    assert_eq!(Fixed32::from_int(-1).sqrt(), Fixed32::ZERO);
+    assert!(close(Fixed32::from_int(2).sqrt().to_f64(), 2f64.sqrt(), 1e-9));
+    assert!(close(Fixed16::PI.to_f64(), PI, 1e-4));
+}
+
+#[test]
+fn trigonometry(){
+    for i in -40..40 {
+        let angle = i as f64 * 0.2;
+        let (sin, cos) = Fixed32::from_f64(angle).sin_cos();
+        assert!(close(sin.to_f64(), angle.sin(), 1e-8), "sin({})", angle);
+        assert!(close(cos.to_f64(), angle.cos(), 1e-8), "cos({})", angle);
+
+        let (sin, cos) = Fixed16::from_f64(angle).sin_cos();
+        assert!(close(sin.to_f64(), angle.sin(), 1e-4), "sin({})", angle);
+        assert!(close(cos.to_f64(), angle.cos(), 1e-4), "cos({})", angle);
+
+        let (y, x) = (angle.sin() * 3., angle.cos() * 3.);
+        let expected = y.atan2(x);
+        let atan2 = Fixed32::from_f64(y).atan2(Fixed32::from_f64(x)).to_f64();
+        assert!(close(atan2, expected, 1e-8), "atan2({}, {})", y, x);
+    }
+    assert_eq!(Fixed16::ZERO.atan2(Fixed16::from_int(-1)), Fixed16::PI);
+    assert_eq!(Fixed16::from_int(-1).atan2(Fixed16::ZERO), -Fixed16::FRAC_PI_2);
+}
+
+#[test]
+fn fixed_vectors(){
+    let v = Vector2(Fixed16::from_int(3), Fixed16::from_int(4));
+
+    assert_eq!(v.length(), Fixed16::from_int(5));
+    assert_eq!(v.length_squared(), Fixed16::from_int(25));
+    assert!(close(v.normalise().length().to_f64(), 1., 1e-4));
+    assert!(close(v.direction().to_f64(), 0.9272952180016122, 1e-4));
+    assert!(close(Vector2(Fixed32::ONE, Fixed32::ONE).direction().to_f64(), FRAC_PI_4, 1e-8));
+    // Would overflow if computed through length_squared
+    let big = Vector2(Fixed16::from_int(20000), Fixed16::from_int(15000));
+    assert_eq!(big.length(), Fixed16::from_int(25000));
+
+    let unit = Vector2::unit_vector(Fixed32::from_f64(0.9272952180016122));
+    assert!(close(unit.0.to_f64(), 0.6, 1e-8) && close(unit.1.to_f64(), 0.8, 1e-8));
+    assert!(v.is_all_finite() && v.is_all_normal() && !v.is_any_nan() && !v.is_any_infinite());
+}
+
+#[test]
+fn fixed_normalisation_and_projection(){
+    use simple_vector2d::NormaliseError;
+
+    let v = Vector2(Fixed16::from_int(3), Fixed16::from_int(4));
+    let zero = Vector2(Fixed16::ZERO, Fixed16::ZERO);
+    let x = Vector2(Fixed16::from_int(2), Fixed16::ZERO);
+
+    assert_eq!(zero.try_normalise(), Err(NormaliseError::ZeroLength));
+    assert_eq!(zero.normalise_or_zero(), zero);
+    let unit = v.try_normalise().unwrap();
+    assert!(close(unit.0.to_f64(), 0.6, 1e-4) && close(unit.1.to_f64(), 0.8, 1e-4));
+    assert_eq!(Vector2(Fixed16::MIN, Fixed16::ZERO).try_normalise(), Ok(Vector2(-Fixed16::ONE, Fixed16::ZERO)));
+    assert_eq!(Vector2(Fixed16::ZERO, Fixed16::MAX).try_normalise(), Ok(Vector2(Fixed16::ZERO, Fixed16::ONE)));
+
+    assert_eq!(v.scalar_projection(x), Fixed16::from_int(3));
+    assert_eq!(v.project_onto(x), Vector2(Fixed16::from_int(3), Fixed16::ZERO));
+    assert_eq!(v.reject_from(zero), v);
+    let floor = Vector2(Fixed16::ZERO, Fixed16::ONE);
+    assert_eq!(Vector2(Fixed16::ONE, -Fixed16::ONE).reflect(floor), Vector2(Fixed16::ONE, Fixed16::ONE));
+}
+
+#[test]
+#[should_panic(expected = "attempt to multiply with overflow")]
+fn multiplication_overflow(){
+    let _ = Fixed16::from_int(200) * Fixed16::from_int(200);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide with overflow")]
+fn division_overflow(){
+    let _ = Fixed16::from_int(20000) / Fixed16::from_f64(0.25);
+}
@@ -0,0 +1,44 @@
+extern crate simple_vector2d;
+
+use simple_vector2d::{Vector2, ParseVectorError};
+use simple_vector2d::fixed::Fixed16;
+
+#[test]
+fn displaying(){
+    assert_eq!(Vector2(1., 2.5).to_string(), "(1, 2.5)");
+    assert_eq!(format!("{:.2}", Vector2(1., 2.5)), "(1.00, 2.50)");
+    assert_eq!(format!("{:>3}", Vector2(1, -2)), "(  1,  -2)");
+    assert_eq!(Vector2(3, 4).debug_display().to_string(), "(3, 4) [length 5, angle 0.9272952180016122]");
+    assert_eq!(format!("{:.1}", Vector2(0., 2.).debug_display()), "(0.0, 2.0) [length 2.0, angle 1.6]");
+    assert_eq!(format!("{:#}", Vector2(3., 4.)), "(3, 4) [length 5, angle 0.9272952180016122]");
+    assert_eq!(format!("{:#.1}", Vector2(0., 2.)), "(0.0, 2.0) [length 2.0, angle 1.6]");
+    assert_eq!(format!("{:#}", Vector2(3u8, 4)), "(3, 4) [length 5, angle 0.9272952180016122]");
+    assert_eq!(format!("{:#}", Vector2(f64::MAX, 0.)), format!("({}, 0) [length {}, angle 0]", f64::MAX, f64::MAX));
+    assert_eq!(Vector2("a", "b").to_string(), "(a, b)");
+    assert_eq!(format!("{:#}", Vector2("a", "b")), "(a, b)");
+    assert_eq!(Vector2(Fixed16::from_f64(0.5), Fixed16::ONE).to_string(), "(0.5, 1)");
+    assert_eq!(Vector2(Fixed16::from_int(3), Fixed16::from_int(4)).debug_display().to_string(),
+        "(3, 4) [length 5, angle 0.9272952180016122]");
+}
+
+#[test]
+fn parsing(){
+    assert_eq!("(1, 2.5)".parse(), Ok(Vector2(1., 2.5)));
+    assert_eq!("[3,-4]".parse(), Ok(Vector2(3, -4)));
+    assert_eq!(" 3 , 4 ".parse(), Ok(Vector2(3u8, 4)));
+    assert_eq!(Vector2(1.25, -3.).to_string().parse(), Ok(Vector2(1.25, -3.)));
+
+    assert_eq!("(1, 2".parse::<Vector2<i32>>(), Err(ParseVectorError::UnmatchedBracket));
+    assert_eq!("[1, 2)".parse::<Vector2<i32>>(), Err(ParseVectorError::UnmatchedBracket));
+    assert_eq!("1, 2]".parse::<Vector2<i32>>(), Err(ParseVectorError::UnmatchedBracket));
+    assert_eq!("()".parse::<Vector2<i32>>(), Err(ParseVectorError::ComponentCount(0)));
+    assert_eq!("1, 2, 3".parse::<Vector2<i32>>(), Err(ParseVectorError::ComponentCount(3)));
+    assert_eq!("7".parse::<Vector2<i32>>(), Err(ParseVectorError::ComponentCount(1)));
+
+    let err = "(1, x)".parse::<Vector2<i32>>().unwrap_err();
+    match err {
+        ParseVectorError::InvalidComponent{index: 1, ..} => (),
+        _ => panic!("unexpected error {:?}", err),
+    }
+    assert_eq!(err.to_string(), "invalid y component in vector: invalid digit found in string");
+}
@@ -0,0 +1,40 @@
+extern crate simple_vector2d;
+
+use simple_vector2d::{IntegerVector, Vector2};
+
+#[test]
+fn overflow_handling(){
+    let max = Vector2(i32::MAX, 0);
+    let one = Vector2(1, 1);
+
+    assert_eq!(max.checked_add(one), None);
+    assert_eq!(one.checked_add(one), Some(Vector2(2, 2)));
+    assert_eq!(Vector2(i32::MIN, 0).checked_sub(one), None);
+    assert_eq!(max.checked_mul(2), None);
+    assert_eq!(one.checked_mul(3), Some(Vector2(3, 3)));
+    assert_eq!(one.checked_div(0), None);
+    assert_eq!(max.wrapping_add(one), Vector2(i32::MIN, 1));
+    assert_eq!(Vector2(i32::MIN, 0).wrapping_sub(one), Vector2(i32::MAX, -1));
+    assert_eq!(max.wrapping_mul(2), Vector2(-2, 0));
+    assert_eq!(max.saturating_add(one), Vector2(i32::MAX, 1));
+    assert_eq!(Vector2(i32::MIN, 0).saturating_sub(one), Vector2(i32::MIN, -1));
+    assert_eq!(max.saturating_mul(2), Vector2(i32::MAX, 0));
+}
+
+#[test]
+fn widened_lengths(){
+    assert_eq!(Vector2(3i32, -4).length_squared(), 25u64);
+    assert_eq!(Vector2(3i32, -4).manhattan_length(), 7u64);
+    assert_eq!(Vector2(i32::MIN, i32::MIN).length_squared(), 1 << 63);
+    assert_eq!(Vector2(i32::MIN, i32::MIN).manhattan_length(), 1 << 32);
+    assert_eq!(Vector2(u8::MAX, u8::MAX).length_squared(), 130050u32);
+    assert_eq!(Vector2(i8::MIN, i8::MIN).manhattan_length(), 256u16);
+}
+
+#[test]
+fn euclidean_chunks(){
+    assert_eq!(Vector2(-1, 17).div_euclid(16), Vector2(-1, 1));
+    assert_eq!(Vector2(-1, 17).rem_euclid(16), Vector2(15, 1));
+    assert_eq!(Vector2(-16, -17).div_euclid(16), Vector2(-1, -2));
+    assert_eq!(Vector2(-16, -17).rem_euclid(16), Vector2(0, 15));
+}
@@ -0,0 +1,60 @@
+#[macro_use]
+extern crate simple_vector2d;
+
+use simple_vector2d::Vector2;
+use simple_vector2d::interpolate::{smoothstep, smootherstep};
+
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+#[test]
+fn linear(){
+    let a = Vector2(0., 10.);
+    let b = Vector2(10., 20.);
+
+    assert_eq!(a.lerp(b, 0.25), Vector2(2.5, 12.5));
+    assert_eq!(a.lerp(b, 2.), b);
+    assert_eq!(a.lerp(b, -1.), a);
+    assert_eq!(a.lerp_unclamped(b, 2.), Vector2(20., 30.));
+    assert_eq!(a.inverse_lerp(b, Vector2(5., 15.)), 0.5);
+    assert_eq!(a.inverse_lerp(b, Vector2(0., 20.)), 0.5);
+    assert_eq!(a.inverse_lerp(b, Vector2(20., 30.)), 2.);
+    assert_eq!(a.inverse_lerp(a, b), 0.);
+}
+
+#[test]
+fn directions(){
+    let right = Vector2(1., 0.);
+    let up = Vector2(0., 1.);
+
+    assert_vec_approx_eq!(right.slerp(up, 0.5), Vector2::unit_vector(FRAC_PI_4));
+    assert_vec_approx_eq!(right.nlerp(up, 0.5), Vector2::unit_vector(FRAC_PI_4));
+    assert_eq!(right.nlerp(-right, 0.5), -right);
+    assert_eq!(right.nlerp(-right * 3., 0.25), right);
+    assert_eq!(right.nlerp(-right, 0.4), right);
+    assert_vec_approx_eq!(right.slerp(up, 1.), up);
+    // Takes the short way across the negative x-axis
+    let a = Vector2::unit_vector(3.);
+    let b = Vector2::unit_vector(-3.);
+    assert_vec_approx_eq!(a.slerp(b, 0.5), Vector2(-1., 0.));
+    assert_vec_approx_eq!((right * 2.).slerp(up * 4., 0.5), Vector2::unit_vector(FRAC_PI_4) * 3.);
+    assert!((right.slerp(up, 1. / 3.).direction() - FRAC_PI_2 / 3.).abs() < 1e-15);
+}
+
+#[test]
+fn easing(){
+    let a = Vector2(0., 0.);
+    let b = Vector2(3., 4.);
+
+    assert_vec_approx_eq!(a.move_towards(b, 1.), Vector2(0.6, 0.8));
+    assert_eq!(a.move_towards(b, 10.), b);
+    assert_eq!(b.move_towards(b, 0.), b);
+
+    assert_eq!(smoothstep(0.5), 0.5);
+    assert_eq!(smoothstep(-1.), 0.);
+    assert_eq!(smoothstep(0.25), 0.15625);
+    assert_eq!(smootherstep(0.5), 0.5);
+    assert_eq!(smootherstep(2.), 1.);
+    assert_eq!(a.smooth_lerp(b, 0.5), Vector2(1.5, 2.));
+    assert_eq!(a.smooth_lerp(b, 0.25), b * 0.15625);
+    assert_eq!(a.smoother_lerp(b, 1.), b);
+}
@@ -0,0 +1,46 @@
+#[macro_use]
+extern crate simple_vector2d;
+
+use simple_vector2d::{Vector2, Vector3, Matrix2, Matrix3};
+
+use std::f64::consts::FRAC_PI_2;
+
+#[test]
+fn linear_transforms(){
+    let v = Vector2(3., 4.);
+    let m = Matrix2(Vector2(1., 2.), Vector2(3., 4.));
+
+    assert_eq!(Matrix2::identity() * v, v);
+    assert_eq!(m * Vector2(1., 1.), Vector2(4., 6.));
+    assert_eq!(m.determinant(), -2.);
+    assert_eq!(m.transpose(), Matrix2(Vector2(1., 3.), Vector2(2., 4.)));
+    assert_eq!(m.inverse().unwrap() * m, Matrix2::identity());
+    assert_eq!(Matrix2(Vector2(1., 2.), Vector2(2., 4.)).inverse(), None);
+    assert_eq!(Matrix2::scale(Vector2(2., 3.)) * v, Vector2(6., 12.));
+    assert_eq!(Matrix2::shear(1., 0.) * v, Vector2(7., 4.));
+    assert_vec_approx_eq!(Matrix2::rotation(FRAC_PI_2) * v, Vector2(-4., 3.));
+    assert_eq!(Matrix2::scale(Vector2(2., 2.)) * m, Matrix2(Vector2(2., 4.), Vector2(6., 8.)));
+}
+
+#[test]
+fn homogeneous_transforms(){
+    let v = Vector2(3., 4.);
+    let translate = Matrix3::translation(Vector2(1., -1.));
+    let rotate = Matrix3::rotation(FRAC_PI_2);
+
+    assert_eq!(translate.transform_point(v), Vector2(4., 3.));
+    assert_eq!(translate.transform_vector(v), v);
+    assert_eq!(Matrix3::scale(Vector2(2., 3.)).transform_point(v), Vector2(6., 12.));
+    assert_eq!(Matrix3::shear(0., 1.).transform_vector(v), Vector2(3., 7.));
+    assert_vec_approx_eq!((translate * rotate).transform_point(v), Vector2(-3., 2.));
+    assert_vec_approx_eq!((rotate * translate).transform_point(v), Vector2(-3., 4.));
+    assert_eq!(translate * Vector3(3., 4., 1.), Vector3(4., 3., 1.));
+
+    let m = translate * Matrix3::scale(Vector2(2., 4.));
+    assert_eq!(m.determinant(), 8.);
+    assert_eq!(m.inverse().unwrap() * m, Matrix3::identity());
+    assert_eq!(m.inverse().unwrap().transform_point(m.transform_point(v)), v);
+    assert_eq!(Matrix3::scale(Vector2(0., 1.)).inverse(), None);
+    assert_eq!(m.transpose().transpose(), m);
+    assert_eq!(Matrix3::from(Matrix2::identity()), Matrix3::<f64>::identity());
+}
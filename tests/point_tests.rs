@@ -0,0 +1,38 @@
+extern crate simple_vector2d;
+
+use simple_vector2d::{Point2, Vector2};
+
+#[test]
+fn affine_arithmetic(){
+    let p0 = Point2(2., -4.);
+    let p1 = Point2(4., -4.);
+    let v = Vector2(1., 2.);
+
+    assert_eq!(p1 - p0, Vector2(2., 0.));
+    assert_eq!(p0 + v, Point2(3., -2.));
+    assert_eq!(p0 - v, Point2(1., -6.));
+
+    let mut p = p0;
+    p += v;
+    assert_eq!(p, Point2(3., -2.));
+    p -= v;
+    assert_eq!(p, p0);
+
+    assert_eq!(p0.distance_to(p1), 2.);
+    assert_eq!(p0.distance_to_squared(p1), 4.);
+    assert_eq!(p0.direction_to(p1), 0.);
+    assert_eq!(Point2(2., 2.).direction_to(Point2(4., 4.)), std::f64::consts::FRAC_PI_4);
+}
+
+#[test]
+fn conversions(){
+    let p = Point2(3., 6.);
+    let v = Vector2(3., 6.);
+
+    assert_eq!(p.to_vector(), v);
+    assert_eq!(v.to_point(), p);
+    assert_eq!(Point2::from(v), p);
+    assert_eq!(Vector2::from(p), v);
+    assert_eq!(Point2::from((3., 6.)), p);
+    assert_eq!(Point2::from([3., 6.]), p);
+}
@@ -0,0 +1,43 @@
+#[macro_use]
+extern crate simple_vector2d;
+
+use simple_vector2d::{Vector2, Polar, LogPolar};
+
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+#[test]
+fn conversions(){
+    let v = Vector2(1., 1.);
+    let p = Polar::from(v);
+
+    assert_eq!(p, Polar::new(2f64.sqrt(), FRAC_PI_4));
+    assert_vec_approx_eq!(Vector2::from(p), v);
+    assert_vec_approx_eq!(Vector2::from(Polar::new(2., FRAC_PI_2)), Vector2(0., 2.));
+
+    let lp = LogPolar::from(Vector2(0., 1.));
+    assert_eq!(lp, LogPolar::new(0., FRAC_PI_2));
+    assert_eq!(Polar::from(LogPolar::new(0., 1.)), Polar::new(1., 1.));
+    assert_vec_approx_eq!(Vector2::from(lp), Vector2(0., 1.));
+}
+
+#[test]
+fn polar_arithmetic(){
+    let p = Polar::new(2., 0.5);
+
+    assert_eq!(p * 3., Polar::new(6., 0.5));
+    assert_eq!(p / 2., Polar::new(1., 0.5));
+    assert_eq!(p.rotate(0.25), Polar::new(2., 0.75));
+    assert_eq!(p * Polar::new(3., 0.25), Polar::new(6., 0.75));
+    assert_eq!(p / Polar::new(4., 0.25), Polar::new(0.5, 0.25));
+
+    let mut q = p;
+    q *= 2.;
+    q /= 4.;
+    assert_eq!(q, Polar::new(1., 0.5));
+
+    // Adding log-polar coordinates multiplies the radii
+    let zoomed: Polar<f64> = Polar::from(LogPolar::from(Polar::new(2., 0.5)) + LogPolar::from(Polar::new(4., 0.25)));
+    assert!((zoomed.radius - 8.).abs() < 1e-12 && zoomed.angle == 0.75);
+    let back: Polar<f64> = Polar::from(LogPolar::from(Polar::new(8., 0.75)) - LogPolar::from(Polar::new(4., 0.25)));
+    assert!((back.radius - 2.).abs() < 1e-12 && back.angle == 0.5);
+}
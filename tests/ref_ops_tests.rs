@@ -0,0 +1,96 @@
+extern crate simple_vector2d;
+
+use simple_vector2d::Vector2;
+
+use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg};
+
+/// A scalar that cannot be copied, like a big rational
+#[derive(Clone, Debug, PartialEq)]
+struct Big(i64);
+
+macro_rules! impl_big {
+    ($($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident;)*) => {$(
+        impl $Op for Big{
+            type Output = Big;
+            fn $op(self, rhs: Big) -> Big{ Big(self.0.$op(rhs.0)) }
+        }
+        impl<'a> $Op<&'a Big> for Big{
+            type Output = Big;
+            fn $op(self, rhs: &'a Big) -> Big{ Big(self.0.$op(rhs.0)) }
+        }
+        impl<'a> $Op<Big> for &'a Big{
+            type Output = Big;
+            fn $op(self, rhs: Big) -> Big{ Big(self.0.$op(rhs.0)) }
+        }
+        impl<'a, 'b> $Op<&'b Big> for &'a Big{
+            type Output = Big;
+            fn $op(self, rhs: &'b Big) -> Big{ Big(self.0.$op(rhs.0)) }
+        }
+        impl<'a> $OpAssign<&'a Big> for Big{
+            fn $op_assign(&mut self, rhs: &'a Big){ self.0.$op_assign(rhs.0) }
+        }
+    )*};
+}
+impl_big!{
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign;
+}
+
+impl Neg for &Big{
+    type Output = Big;
+    fn neg(self) -> Big{ Big(-self.0) }
+}
+
+fn big(x: i64, y: i64) -> Vector2<Big>{
+    Vector2(Big(x), Big(y))
+}
+
+#[test]
+fn borrowed_operands(){
+    let a = big(6, 8);
+    let b = big(2, 4);
+    let two = Big(2);
+
+    assert_eq!(&a + &b, big(8, 12));
+    assert_eq!(&a - b.clone(), big(4, 4));
+    assert_eq!(a.clone() + &b, big(8, 12));
+    assert_eq!(&a * &b, big(12, 32));
+    assert_eq!(&a / &b, big(3, 2));
+    assert_eq!(&a * &two, big(12, 16));
+    assert_eq!(a.clone() / &two, big(3, 4));
+    assert_eq!(-&a, big(-6, -8));
+    assert_eq!(a.dot_ref(&b), Big(44));
+    assert_eq!(a.det_ref(&b), Big(8));
+}
+
+#[test]
+fn borrowed_assignment(){
+    let mut v = big(6, 8);
+    v += &big(1, 1);
+    assert_eq!(v, big(7, 9));
+    v -= &big(3, 1);
+    assert_eq!(v, big(4, 8));
+    v *= &Big(3);
+    assert_eq!(v, big(12, 24));
+    v /= &Big(4);
+    assert_eq!(v, big(3, 6));
+    v *= &big(2, 3);
+    assert_eq!(v, big(6, 18));
+    v /= &big(3, 9);
+    assert_eq!(v, big(2, 2));
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn copyable_scalars(){
+    let a = Vector2(1., 2.);
+    let b = Vector2(3., 4.);
+
+    assert_eq!(&a + &b, a + b);
+    assert_eq!(&a - b, a - b);
+    assert_eq!(a * &2., a * 2.);
+    assert_eq!(-&a, -a);
+    assert_eq!(a.dot_ref(&b), a.dot(b));
+}
@@ -0,0 +1,48 @@
+extern crate simple_vector2d;
+
+use simple_vector2d::{Vector2, Rotation2};
+
+use std::f64::consts::{PI, FRAC_PI_2, FRAC_PI_4};
+
+fn close(a: Vector2<f64>, b: Vector2<f64>) -> bool{
+    (a - b).length() < 1e-12
+}
+
+#[test]
+fn rotating_vectors(){
+    let v = Vector2(1., 0.);
+
+    assert!(close(v.rotate(FRAC_PI_2), Vector2(0., 1.)));
+    assert!(close(v.rotate(PI), Vector2(-1., 0.)));
+    assert!(close(Vector2(2., 1.).rotate_around(Vector2(1., 1.), FRAC_PI_2), Vector2(1., 2.)));
+    assert!(close(Vector2(3., 4.).rotate_by_unit(Vector2::unit_vector(0.5)), Vector2(3., 4.).rotate(0.5)));
+}
+
+#[test]
+fn rotation_type(){
+    let quarter = Rotation2::new(FRAC_PI_2);
+    let eighth = Rotation2::new(FRAC_PI_4);
+
+    assert!(close(quarter * Vector2(1., 0.), Vector2(0., 1.)));
+    assert!((quarter.angle() - FRAC_PI_2).abs() < 1e-12);
+    assert!(((eighth * eighth).angle() - FRAC_PI_2).abs() < 1e-12);
+    assert!(close(quarter.inverse() * (quarter * Vector2(3., 4.)), Vector2(3., 4.)));
+    assert!(close((quarter * quarter.inverse()).to_vector(), Rotation2::identity().to_vector()));
+    assert!(close(Rotation2::from_vector(Vector2(0., 5.)).unwrap().to_vector(), quarter.to_vector()));
+    assert_eq!(Rotation2::from_vector(Vector2(0., 0.)), None);
+    assert_eq!(Rotation2::from_vector(Vector2(f64::NAN, 1.)), None);
+
+    let mut r = eighth;
+    r *= eighth;
+    assert!((r.angle() - FRAC_PI_2).abs() < 1e-12);
+}
+
+#[test]
+fn interpolating_rotations(){
+    let a = Rotation2::new(0.1);
+    let b = Rotation2::new(-0.1 + 2. * PI);
+
+    assert!((a.slerp(b, 0.5).angle()).abs() < 1e-12);
+    assert!((a.slerp(b, 0.).angle() - 0.1).abs() < 1e-12);
+    assert!((a.slerp(b, 1.).angle() + 0.1).abs() < 1e-12);
+}
@@ -0,0 +1,53 @@
+extern crate simple_vector2d;
+
+use simple_vector2d::{Vector2, TotalVector2};
+
+use std::collections::{HashMap, HashSet};
+
+#[test]
+fn canonicalising(){
+    assert_eq!(Vector2(-0., 1.).total(), Vector2(0., 1.).total());
+    assert_eq!(Vector2(f64::NAN, 1.).total(), Vector2(-f64::NAN, 1.).total());
+    assert!(Vector2(-0f64, 1.).total().get().0.is_sign_positive());
+    assert!(Vector2(-f32::NAN, 1.).total().get().0.is_sign_positive());
+    assert!(Vector2(1., f64::NAN).total() != Vector2(1., 2.).total());
+}
+
+#[test]
+fn total_ordering(){
+    let mut v: Vec<TotalVector2<f64>> = vec![
+        Vector2(f64::NAN, 0.),
+        Vector2(1., 2.),
+        Vector2(f64::INFINITY, 0.),
+        Vector2(-0., 5.),
+        Vector2(1., -2.),
+        Vector2(f64::NEG_INFINITY, 0.),
+        Vector2(-1.5, 0.),
+    ].into_iter().map(TotalVector2::from).collect();
+    v.sort();
+    let sorted: Vec<Vector2<f64>> = v.into_iter().map(Vector2::from).collect();
+    assert_eq!(&sorted[..6], &[
+        Vector2(f64::NEG_INFINITY, 0.),
+        Vector2(-1.5, 0.),
+        Vector2(0., 5.),
+        Vector2(1., -2.),
+        Vector2(1., 2.),
+        Vector2(f64::INFINITY, 0.),
+    ]);
+    assert!(sorted[6].0.is_nan());
+}
+
+#[test]
+fn hashing(){
+    let vertices = vec![Vector2(0f32, 1.), Vector2(-0., 1.), Vector2(2., 3.), Vector2(0., 1.)];
+    let unique: HashSet<_> = vertices.iter().map(|&v| v.total()).collect();
+    assert_eq!(unique.len(), 2);
+
+    let mut indices = HashMap::new();
+    for v in vertices {
+        let next = indices.len();
+        indices.entry(v.total()).or_insert(next);
+    }
+    assert_eq!(indices[&Vector2(0., 1.).total()], 0);
+    assert_eq!(indices[&Vector2(2., 3.).total()], 1);
+}
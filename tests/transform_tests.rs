@@ -0,0 +1,54 @@
+#[macro_use]
+extern crate simple_vector2d;
+
+use simple_vector2d::{Vector2, Vector3, Matrix3, Transform2};
+
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+#[test]
+fn applying_transforms(){
+    let t = Transform2::new(Vector2(10., 0.), FRAC_PI_2, 2.);
+    let v = Vector2(1., 0.);
+
+    assert_vec_approx_eq!(t.transform_point(v), Vector2(10., 2.));
+    assert_vec_approx_eq!(t.transform_vector(v), Vector2(0., 2.));
+    assert_eq!(Transform2::identity().transform_point(v), v);
+    assert_vec_approx_eq!(t.inverse().transform_point(t.transform_point(Vector2(3., 4.))), Vector2(3., 4.));
+
+    let u = Transform2::new(Vector2(-1., 5.), FRAC_PI_4, 0.5);
+    assert_vec_approx_eq!((t * u).transform_point(v), t.transform_point(u.transform_point(v)), epsilon = 1e-12);
+    assert_vec_approx_eq!((t * t.inverse()).transform_point(Vector2(3., 4.)), Vector2(3., 4.));
+
+    let mut w = t;
+    w *= u;
+    assert_eq!(w, t * u);
+}
+
+#[test]
+fn interpolating_transforms(){
+    let a = Transform2::new(Vector2(0., 0.), 0., 1.);
+    let b = Transform2::new(Vector2(10., 20.), FRAC_PI_2, 3.);
+    let mid = a.lerp(b, 0.5);
+
+    assert_eq!(mid.translation, Vector2(5., 10.));
+    assert!((mid.rotation.angle() - FRAC_PI_4).abs() < 1e-15);
+    assert_eq!(mid.scale, 2.);
+    assert_eq!(a.lerp(b, 1.), b);
+}
+
+#[test]
+fn matrix_conversions(){
+    let t = Transform2::new(Vector2(10f64, -3.), 0.5, 2.);
+    let m = Matrix3::from(t);
+
+    assert_vec_approx_eq!(m.transform_point(Vector2(3., 4.)), t.transform_point(Vector2(3., 4.)));
+    let back = Transform2::from_matrix(m).unwrap();
+    assert_vec_approx_eq!(back.translation, t.translation);
+    assert!((back.rotation.angle() - 0.5).abs() < 1e-15);
+    assert!((back.scale - 2.).abs() < 1e-15);
+
+    let mirrored = Matrix3::scale(Vector2(-1., 1.));
+    assert_eq!(Transform2::from_matrix(mirrored), None);
+    let projective = Matrix3(Vector3(1., 0., 1.), Vector3(0., 1., 0.), Vector3(0., 0., 1.));
+    assert_eq!(Transform2::from_matrix(projective), None);
+}
@@ -7,7 +7,7 @@ fn feminism(){
     let v0 = Vector2::from((3., 6.));
     let v1 = Vector2::from([3., 6.]);
     let something_different = Vector2(455., 1.2);
-    let nan = Vector2(4., 0./0.);
+    let nan = Vector2(4., f64::NAN);
 
     assert_eq!(v0, v1);
     assert_eq!(v1, v0);
@@ -49,9 +49,105 @@ fn following_directions(){
     assert_eq!(v.normalise().length(), 1.);
     assert_eq!(v.normalise().length_squared(), 1.);
     assert_eq!(v.direction(), 0.9272952180016122);
-    assert!((v.normalise()-Vector2::unit_vector(0.9272952180016122)).length() < std::f64::EPSILON);
+    assert!((v.normalise()-Vector2::unit_vector(0.9272952180016122)).length() < f64::EPSILON);
     assert_eq!(Vector2(1., 1.).direction(), std::f64::consts::FRAC_PI_4);
     assert_eq!(Vector2(2., -4.).distance_to(Vector2(4., -4.)), 2.);
     assert_eq!(Vector2(2., -4.).direction_to(Vector2(4., -4.)), 0.);
     assert_eq!(Vector2(2., 2.).direction_to(Vector2(4., 4.)), std::f64::consts::FRAC_PI_4);
 }
+
+#[test]
+fn careful_normalisation(){
+    use simple_vector2d::NormaliseError;
+
+    let v = Vector2(3., 4.);
+    let zero = Vector2(0., 0.);
+
+    assert_eq!(v.try_normalise(), Ok(Vector2(0.6, 0.8)));
+    assert_eq!(zero.try_normalise(), Err(NormaliseError::ZeroLength));
+    assert_eq!(Vector2(f64::NAN, 1.).try_normalise(), Err(NormaliseError::NonFinite));
+    assert_eq!(Vector2(f64::INFINITY, 1.).try_normalise(), Err(NormaliseError::NonFinite));
+    assert_eq!(Vector2(f64::MAX, 0.).try_normalise(), Ok(Vector2(1., 0.)));
+    assert_eq!(Vector2(0., -5e-324).try_normalise(), Ok(Vector2(0., -1.)));
+    assert_eq!(Vector2(1e-200, 1e-200).try_normalise(), Vector2(1., 1.).try_normalise());
+    assert_eq!(Vector2(f64::MAX, f64::MAX).try_normalise(), Vector2(1., 1.).try_normalise());
+    assert_eq!(zero.normalise_or(Vector2(1., 0.)), Vector2(1., 0.));
+    assert_eq!(v.normalise_or(Vector2(1., 0.)), Vector2(0.6, 0.8));
+    assert_eq!(zero.normalise_or_zero(), zero);
+    assert_eq!(v.normalise_and_length(), (Vector2(0.6, 0.8), 5.));
+    assert_eq!(Vector2(-3., 4.).normalise_and_length(), (Vector2(-0.6, 0.8), 5.));
+    assert_eq!(Vector2(1e200, 0.).normalise_and_length(), (Vector2(1., 0.), 1e200));
+    assert_eq!(zero.normalise_and_length().1, 0.);
+    assert!(zero.normalise_and_length().0.is_any_nan());
+    assert_eq!(Vector2(f64::INFINITY, 0.).normalise_and_length().1, f64::INFINITY);
+}
+
+#[test]
+fn bouncing_around(){
+    let v = Vector2(3., 4.);
+    let x = Vector2(2., 0.);
+    let zero = Vector2(0., 0.);
+
+    assert_eq!(v.scalar_projection(x), 3.);
+    assert_eq!(v.project_onto(x), Vector2(3., 0.));
+    assert_eq!(v.reject_from(x), Vector2(0., 4.));
+    assert_eq!(v.scalar_projection(zero), 0.);
+    assert_eq!(v.project_onto(zero), zero);
+    assert_eq!(v.reject_from(zero), v);
+
+    let floor = Vector2(1., 0.).normal();
+    assert_eq!(Vector2(1., -1.).reflect(floor), Vector2(1., 1.));
+    assert_eq!(Vector2(1., -1.).slide(floor), Vector2(1., 0.));
+
+    let down = Vector2(0., -1.);
+    assert_eq!(down.refract(floor, 1.5), Some(down));
+    let grazing = Vector2(1., -0.1).normalise();
+    assert_eq!(grazing.refract(floor, 1.5), None);
+    let refracted = Vector2(1f64, -1.).normalise().refract(floor, 0.5).unwrap();
+    assert!((refracted.length() - 1.).abs() < 1e-15);
+    assert!((refracted.0 - 0.5 * std::f64::consts::FRAC_1_SQRT_2).abs() < 1e-15);
+}
+
+#[test]
+fn constants(){
+    use simple_vector2d::consts::{UP_F32, LEFT_F64};
+    use simple_vector2d::fixed::Fixed16;
+
+    assert_eq!(Vector2::<f32>::Y, UP_F32);
+    assert_eq!(Vector2::<f64>::NEG_X, LEFT_F64);
+    assert_eq!(Vector2::<i32>::ZERO, Vector2(0, 0));
+    assert_eq!(Vector2::<u8>::ONE, Vector2(1, 1));
+    assert_eq!(Vector2::<i16>::X + Vector2::Y, Vector2(1, 1));
+    assert_eq!(Vector2::<i64>::NEG_Y, Vector2(0, -1));
+    assert_eq!(Vector2::<u16>::MAX, Vector2(u16::MAX, u16::MAX));
+    assert_eq!(Vector2::<i8>::MIN, Vector2(i8::MIN, i8::MIN));
+    assert!(Vector2::<f64>::NAN.is_any_nan());
+    assert!(Vector2::<f32>::INFINITY.is_any_infinite());
+    assert_eq!(Vector2::<f64>::NEG_INFINITY, -Vector2::<f64>::INFINITY);
+    assert_eq!(Vector2::<Fixed16>::NEG_X, Vector2(-Fixed16::ONE, Fixed16::ZERO));
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn scalars_on_the_left(){
+    use simple_vector2d::ApproxEq;
+
+    let v = Vector2(3, 4);
+
+    assert_eq!(2 * v, Vector2(6, 8));
+    assert_eq!(2u8 * Vector2(3u8, 4), Vector2(6, 8));
+    assert_eq!(-1i64 * Vector2(3i64, 4), Vector2(-3, -4));
+    assert_eq!(12 / v, Vector2(4, 3));
+    assert_eq!(1. / Vector2(2., 4.), Vector2(0.5, 0.25));
+    assert!((3. / (3. / Vector2(0.7f64, 11.))).ulps_eq(&Vector2(0.7, 11.), 0., 4));
+    assert_eq!(&2 * v, 2 * v);
+    assert_eq!(2 * &v, 2 * v);
+    assert_eq!(&2 * &v, 2 * v);
+    assert_eq!(&12 / &v, 12 / v);
+    assert_eq!(2usize * simple_vector2d::Vector3(1, 2, 3), simple_vector2d::Vector3(2, 4, 6));
+
+    let mut w = v;
+    w *= 2;
+    w /= &4;
+    assert_eq!(w, Vector2(1, 2));
+}